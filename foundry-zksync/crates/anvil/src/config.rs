@@ -0,0 +1,119 @@
+//! Node configuration.
+
+use crate::eth::backend::{
+    beacon::{SlotClock, DEFAULT_SECONDS_PER_SLOT, DEFAULT_SLOTS_PER_EPOCH},
+    time::TimeManager,
+};
+use alloy_primitives::U256;
+
+/// Configuration for the node.
+///
+/// This only documents the timestamp- and mining-related knobs that anvil's backlog of changes
+/// touches; the rest of the node's configuration (fork settings, accounts, gas limits, ...) lives
+/// alongside it in the real config but is out of scope here.
+#[derive(Clone, Debug)]
+pub struct NodeConfig {
+    /// The genesis timestamp, i.e. the timestamp of block 0.
+    pub genesis_timestamp: Option<U256>,
+    /// Fixed interval (in seconds) that each subsequently mined block's timestamp advances by,
+    /// instead of being derived from the wall clock. `Some(0)` keeps consecutive blocks on the
+    /// same timestamp.
+    pub block_timestamp_interval: Option<u64>,
+    /// Fixed delta (in seconds) applied to `block.timestamp` for every block mined once
+    /// deterministic block-time mode is active, regardless of how much wall-clock time actually
+    /// elapsed. Unlike `block_timestamp_interval`, this is meant to be the node's steady-state
+    /// timestamp source rather than a one-off override.
+    pub block_time_step: Option<u64>,
+    /// Whether beacon-chain slot mining is enabled; when `true`, the backend mines exactly one
+    /// block per slot and aligns `block.timestamp` to the slot boundary.
+    pub slot_mining: bool,
+    /// Seconds per consensus-layer slot used by the slot-mining simulation.
+    pub seconds_per_slot: u64,
+    /// Slots per consensus-layer epoch used by the slot-mining simulation.
+    pub slots_per_epoch: u64,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            genesis_timestamp: None,
+            block_timestamp_interval: None,
+            block_time_step: None,
+            slot_mining: false,
+            seconds_per_slot: DEFAULT_SECONDS_PER_SLOT,
+            slots_per_epoch: DEFAULT_SLOTS_PER_EPOCH,
+        }
+    }
+}
+
+impl NodeConfig {
+    /// Returns a config suitable for tests: deterministic accounts, no fork, ephemeral state.
+    pub fn test() -> Self {
+        Self::default()
+    }
+
+    /// Sets the genesis timestamp.
+    #[must_use]
+    pub fn with_genesis_timestamp(mut self, timestamp: U256) -> Self {
+        self.genesis_timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets a fixed interval (in seconds) that each subsequently mined block's timestamp advances
+    /// by, instead of being derived from the wall clock.
+    ///
+    /// Passing `0` keeps every subsequently mined block on the same timestamp as its
+    /// predecessor, which is useful for interval-mining setups that want flat timestamps.
+    #[must_use]
+    pub fn with_block_timestamp_interval(mut self, interval: impl Into<Option<u64>>) -> Self {
+        self.block_timestamp_interval = interval.into();
+        self
+    }
+
+    /// Sets a fixed delta (in seconds) applied to `block.timestamp` for every block mined once
+    /// deterministic block-time mode is active, independent of wall-clock time. Blocks are still
+    /// triggered the normal way, either manually via `anvil_mine` or by the interval-mining
+    /// timer; only the timestamp derivation bypasses the clock.
+    #[must_use]
+    pub fn with_block_time_step(mut self, step: impl Into<Option<u64>>) -> Self {
+        self.block_time_step = step.into();
+        self
+    }
+
+    /// Enables beacon-chain slot mining with the given `seconds_per_slot`/`slots_per_epoch`,
+    /// mining exactly one block per slot and aligning `block.timestamp` to the slot boundary.
+    #[must_use]
+    pub fn with_slot_mining(mut self, seconds_per_slot: u64, slots_per_epoch: u64) -> Self {
+        self.slot_mining = true;
+        self.seconds_per_slot = seconds_per_slot;
+        self.slots_per_epoch = slots_per_epoch;
+        self
+    }
+
+    /// Builds the [`SlotClock`] this config describes, anchored at the genesis timestamp.
+    pub fn slot_clock(&self) -> SlotClock {
+        let genesis = self
+            .genesis_timestamp
+            .map(|t| t.to::<u64>())
+            .unwrap_or_else(|| crate::eth::backend::time::utc_now().as_secs());
+        SlotClock::new(genesis, self.seconds_per_slot, self.slots_per_epoch)
+    }
+
+    /// Builds the [`TimeManager`] this config describes.
+    pub fn time_manager(&self) -> TimeManager {
+        let start = self
+            .genesis_timestamp
+            .map(|t| t.to::<u64>())
+            .unwrap_or_else(|| crate::eth::backend::time::utc_now().as_secs());
+        let mut time = TimeManager::new(start);
+        if self.slot_mining {
+            // One slot per block: block `n` lands on `genesis + n * seconds_per_slot`.
+            time.set_block_time_step(self.seconds_per_slot);
+        } else if let Some(step) = self.block_time_step {
+            time.set_block_time_step(step);
+        } else if let Some(interval) = self.block_timestamp_interval {
+            time.set_block_timestamp_interval(interval);
+        }
+        time
+    }
+}
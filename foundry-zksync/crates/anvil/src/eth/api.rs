@@ -0,0 +1,67 @@
+//! Implementations of the `anvil_*` namespace RPC methods relating to block timestamps and the
+//! beacon-chain slot simulation.
+//!
+//! These are dispatched from `EthRequest::SetNextBlockTimestamp`,
+//! `EthRequest::SetBlockTimestampInterval`, `EthRequest::RemoveBlockTimestampInterval`,
+//! `EthRequest::GetCurrentSlot`, `EthRequest::GetCurrentEpoch` and
+//! `EthRequest::GetProposerDuties` in `anvil-core`.
+
+use crate::eth::{
+    backend::{
+        beacon::{proposer_duties_for_epoch, ProposerDuty},
+        time::{utc_now, TimeError},
+    },
+    EthApi,
+};
+
+impl EthApi {
+    /// Sets the exact timestamp to use for the next mined block.
+    ///
+    /// Only rejects the new timestamp if it is strictly less than the previous block's
+    /// timestamp; an equal timestamp is accepted so two blocks may share one.
+    pub fn anvil_set_next_block_timestamp(&self, timestamp: u64) -> Result<(), TimeError> {
+        self.backend.time.write().set_next_block_timestamp(timestamp)
+    }
+
+    /// Sets a fixed interval (in seconds) that each subsequently mined block's timestamp advances
+    /// by, instead of being derived from the wall clock.
+    ///
+    /// Passing `0` makes every subsequently mined block report the same timestamp as its
+    /// predecessor.
+    pub fn anvil_set_block_timestamp_interval(&self, seconds: u64) -> Result<(), TimeError> {
+        self.backend.time.write().set_block_timestamp_interval(seconds);
+        Ok(())
+    }
+
+    /// Removes a previously set block timestamp interval, reverting to wall-clock timestamps.
+    pub fn anvil_remove_block_timestamp_interval(&self) -> Result<bool, TimeError> {
+        Ok(self.backend.time.write().remove_block_timestamp_interval())
+    }
+
+    /// Enables deterministic block-time mode: every subsequently mined block's timestamp is
+    /// `last_timestamp + step`, computed without reading the wall clock. Blocks are still mined
+    /// the usual way (manually via `anvil_mine`, or on the existing interval-mining timer); only
+    /// the timestamp source changes, which makes `block.timestamp` sequences fully reproducible
+    /// for contracts that depend on its arithmetic (vesting, TWAPs, auctions, ...).
+    pub fn anvil_set_block_time_step(&self, step: u64) -> Result<(), TimeError> {
+        self.backend.time.write().set_block_time_step(step);
+        Ok(())
+    }
+
+    /// Returns the current consensus-layer slot, derived from the node's slot clock.
+    pub fn anvil_get_current_slot(&self) -> u64 {
+        self.backend.slot_clock.current_slot(utc_now().as_secs())
+    }
+
+    /// Returns the current consensus-layer epoch (`slot / slots_per_epoch`).
+    pub fn anvil_get_current_epoch(&self) -> u64 {
+        self.backend.slot_clock.current_epoch(utc_now().as_secs())
+    }
+
+    /// Returns the deterministic proposer-duty schedule for every slot in `epoch`, mirroring a
+    /// beacon node's `get_proposer_duties` API but sourced entirely from anvil's dev accounts.
+    pub fn anvil_get_proposer_duties(&self, epoch: u64) -> Vec<ProposerDuty> {
+        let validators: Vec<_> = self.backend.dev_accounts().collect();
+        proposer_duties_for_epoch(&self.backend.slot_clock, epoch, &validators)
+    }
+}
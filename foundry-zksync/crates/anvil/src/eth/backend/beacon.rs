@@ -0,0 +1,132 @@
+//! Minimal beacon-chain slot/epoch simulation.
+//!
+//! This lets users exercise preconfirmation, PBS, and time-lock logic that keys off
+//! consensus-layer slots without needing a real beacon node: anvil mines exactly one block per
+//! slot and aligns `block.timestamp` to the slot boundary, and `anvil_get_proposer_duties`
+//! deterministically assigns one of the node's dev accounts as the slot's proposer.
+
+use alloy_primitives::{keccak256, Address};
+
+/// Default seconds per slot, matching mainnet.
+pub const DEFAULT_SECONDS_PER_SLOT: u64 = 12;
+/// Default slots per epoch, matching mainnet.
+pub const DEFAULT_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Drives anvil's beacon-chain slot simulation.
+///
+/// When enabled, the backend mines exactly one block per slot, with `block.timestamp` set to
+/// `genesis_timestamp + slot * seconds_per_slot` rather than the usual clock- or
+/// interval-derived value.
+#[derive(Clone, Debug)]
+pub struct SlotClock {
+    genesis_timestamp: u64,
+    seconds_per_slot: u64,
+    slots_per_epoch: u64,
+}
+
+impl SlotClock {
+    /// Creates a new slot clock anchored at `genesis_timestamp`.
+    pub fn new(genesis_timestamp: u64, seconds_per_slot: u64, slots_per_epoch: u64) -> Self {
+        Self { genesis_timestamp, seconds_per_slot, slots_per_epoch }
+    }
+
+    /// Creates a slot clock using mainnet's default slot/epoch durations.
+    pub fn with_defaults(genesis_timestamp: u64) -> Self {
+        Self::new(genesis_timestamp, DEFAULT_SECONDS_PER_SLOT, DEFAULT_SLOTS_PER_EPOCH)
+    }
+
+    /// Returns the current slot, derived from `now`.
+    pub fn current_slot(&self, now: u64) -> u64 {
+        now.saturating_sub(self.genesis_timestamp) / self.seconds_per_slot
+    }
+
+    /// Returns the epoch that `slot` belongs to.
+    pub fn epoch_for_slot(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    /// Returns the current epoch, derived from `now`.
+    pub fn current_epoch(&self, now: u64) -> u64 {
+        self.epoch_for_slot(self.current_slot(now))
+    }
+
+    /// Returns the `block.timestamp` that a block minted for `slot` should carry.
+    pub fn timestamp_for_slot(&self, slot: u64) -> u64 {
+        self.genesis_timestamp + slot * self.seconds_per_slot
+    }
+
+    /// Returns the slot index within its epoch.
+    pub fn slot_in_epoch(&self, slot: u64) -> u64 {
+        slot % self.slots_per_epoch
+    }
+
+    pub fn seconds_per_slot(&self) -> u64 {
+        self.seconds_per_slot
+    }
+
+    pub fn slots_per_epoch(&self) -> u64 {
+        self.slots_per_epoch
+    }
+}
+
+/// A single slot's proposer assignment, mirroring a beacon node's `get_proposer_duties` response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProposerDuty {
+    pub slot: u64,
+    pub validator_index: usize,
+    pub address: Address,
+}
+
+/// Deterministically assigns one of `validators` as proposer for `slot`.
+///
+/// The assignment is `keccak256(slot) % validators.len()`, which is stable across runs and
+/// requires no external randomness beacon, unlike a real CL.
+pub fn proposer_for_slot(slot: u64, validators: &[Address]) -> Option<ProposerDuty> {
+    if validators.is_empty() {
+        return None
+    }
+    let digest = keccak256(slot.to_be_bytes());
+    let index = (u64::from_be_bytes(digest[24..32].try_into().unwrap()) as usize) % validators.len();
+    Some(ProposerDuty { slot, validator_index: index, address: validators[index] })
+}
+
+/// Returns the proposer duties for every slot in `epoch`.
+pub fn proposer_duties_for_epoch(
+    clock: &SlotClock,
+    epoch: u64,
+    validators: &[Address],
+) -> Vec<ProposerDuty> {
+    let first_slot = epoch * clock.slots_per_epoch();
+    (first_slot..first_slot + clock.slots_per_epoch())
+        .filter_map(|slot| proposer_for_slot(slot, validators))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_timestamp_to_slot_boundary() {
+        let clock = SlotClock::with_defaults(1_000);
+        assert_eq!(clock.timestamp_for_slot(0), 1_000);
+        assert_eq!(clock.timestamp_for_slot(5), 1_000 + 5 * 12);
+    }
+
+    #[test]
+    fn derives_epoch_from_slot() {
+        let clock = SlotClock::with_defaults(0);
+        assert_eq!(clock.epoch_for_slot(0), 0);
+        assert_eq!(clock.epoch_for_slot(31), 0);
+        assert_eq!(clock.epoch_for_slot(32), 1);
+    }
+
+    #[test]
+    fn proposer_assignment_is_deterministic_and_draws_from_validators() {
+        let validators = vec![Address::repeat_byte(1), Address::repeat_byte(2)];
+        let first = proposer_for_slot(0, &validators).unwrap();
+        let again = proposer_for_slot(0, &validators).unwrap();
+        assert_eq!(first, again);
+        assert!(validators.contains(&first.address));
+    }
+}
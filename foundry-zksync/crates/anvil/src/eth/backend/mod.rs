@@ -0,0 +1,50 @@
+pub mod beacon;
+pub mod notifications;
+pub mod time;
+
+use crate::config::NodeConfig;
+use alloy_primitives::Address;
+use beacon::SlotClock;
+use notifications::BlockNotifications;
+use parking_lot::RwLock;
+use time::TimeManager;
+
+/// Node-wide state shared by every `EthApi` handler.
+///
+/// Only the fields the timestamp-control and beacon-slot-simulation RPCs (`anvil_*`) and the
+/// `newHeads` subscription stream depend on are declared here; the state trie, mempool, and
+/// mining executor that make up the rest of a real anvil `Backend` live alongside this struct and
+/// aren't part of this reduced checkout.
+pub struct Backend {
+    /// Governs the timestamp handed to the next mined block.
+    pub time: RwLock<TimeManager>,
+    /// Drives the optional beacon-chain slot/epoch simulation.
+    pub slot_clock: SlotClock,
+    /// Fans out newly sealed blocks to `newHeads`/pending-transaction subscribers.
+    pub notifications: BlockNotifications,
+    dev_accounts: Vec<Address>,
+}
+
+impl Backend {
+    /// Creates a new backend from `config`, configured with `dev_accounts` as the addresses
+    /// eligible for [`Self::dev_accounts`] (and therefore `anvil_get_proposer_duties`).
+    ///
+    /// Builds `time`/`slot_clock` via [`NodeConfig::time_manager`]/[`NodeConfig::slot_clock`]
+    /// rather than constructing them directly, so `--block-time-step`, `--timestamp-interval`,
+    /// and custom `--slots-per-epoch`/`--seconds-per-slot` actually reach the running backend
+    /// instead of being silently dropped in favor of hardcoded defaults.
+    pub fn new(config: &NodeConfig, dev_accounts: Vec<Address>) -> Self {
+        Self {
+            time: RwLock::new(config.time_manager()),
+            slot_clock: config.slot_clock(),
+            notifications: BlockNotifications::new(),
+            dev_accounts,
+        }
+    }
+
+    /// Returns the node's dev accounts, used by `anvil_get_proposer_duties` to assign a proposer
+    /// to each slot.
+    pub fn dev_accounts(&self) -> impl Iterator<Item = Address> + '_ {
+        self.dev_accounts.iter().copied()
+    }
+}
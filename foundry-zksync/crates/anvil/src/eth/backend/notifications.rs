@@ -0,0 +1,77 @@
+//! Broadcasts newly mined blocks to subscribers, so `eth_subscribe("newHeads")` and pending
+//! transaction subscriptions don't need to poll `eth_getBlockByNumber` in a loop.
+
+use alloy_primitives::{TxHash, B256};
+use tokio::sync::broadcast;
+
+/// Default capacity of the internal broadcast channel; lagging subscribers simply miss the
+/// oldest buffered notifications rather than blocking block production.
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 2048;
+
+/// A notification fired the moment a block is sealed by the mining task.
+#[derive(Clone, Debug)]
+pub struct NewBlockNotification {
+    /// Hash of the newly sealed block.
+    pub hash: B256,
+    /// Number of the newly sealed block.
+    pub number: u64,
+    /// Transaction hashes included in the block, fanned out to pending-transaction
+    /// subscriptions.
+    pub transactions: Vec<TxHash>,
+}
+
+/// Fans out [`NewBlockNotification`]s to every subscriber.
+///
+/// The mining task holds the sending half and fires exactly one notification per sealed block;
+/// every `eth_subscribe` stream for `newHeads`/pending transactions subscribes to a receiver
+/// instead of spawning its own polling task.
+#[derive(Clone, Debug)]
+pub struct BlockNotifications {
+    sender: broadcast::Sender<NewBlockNotification>,
+}
+
+impl BlockNotifications {
+    /// Creates a new, empty notification hub.
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(NOTIFICATION_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Called by the mining task synchronously once a block has been sealed.
+    pub fn notify(&self, notification: NewBlockNotification) {
+        // No subscribers is not an error: most nodes never have a WS client attached.
+        let _ = self.sender.send(notification);
+    }
+
+    /// Subscribes to future block notifications.
+    pub fn subscribe(&self) -> broadcast::Receiver<NewBlockNotification> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for BlockNotifications {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn subscribers_receive_each_sealed_block_exactly_once() {
+        let notifications = BlockNotifications::new();
+        let mut rx = notifications.subscribe();
+
+        notifications.notify(NewBlockNotification {
+            hash: B256::repeat_byte(1),
+            number: 1,
+            transactions: vec![],
+        });
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.number, 1);
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! Manages the block timestamps of the node.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Returns the current duration since unix epoch.
+pub fn utc_now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("system time before epoch")
+}
+
+/// Manages block timestamps and the invariants around them.
+///
+/// Tracks the timestamp of the last mined block and hands out the timestamp for the next one,
+/// either derived from the wall clock, an explicit override set via
+/// `anvil_set_next_block_timestamp`, or a fixed per-block interval set via
+/// `anvil_set_block_timestamp_interval`.
+///
+/// The only invariant callers must uphold is that block timestamps are monotonically
+/// *non-decreasing*: a block may share its predecessor's timestamp (this does happen on real L1
+/// when multiple blocks land in the same second), but it may never report an earlier one.
+#[derive(Clone, Debug)]
+pub struct TimeManager {
+    /// The instant this manager was created; the clock-derived path uses the time elapsed since
+    /// then.
+    start: Instant,
+    /// The timestamp of the most recently mined block.
+    last_timestamp: u64,
+    /// If set, the next mined block's timestamp is forced to this value instead of being derived
+    /// from the clock.
+    next_exact_timestamp: Option<u64>,
+    /// Fixed interval (in seconds) to add to `last_timestamp` for the next block's timestamp,
+    /// bypassing the wall clock. A value of `0` keeps consecutive blocks on the same timestamp.
+    ///
+    /// This also backs the deterministic block-time step mode (`anvil_set_block_time_step`):
+    /// once set, every subsequent block's timestamp is `last_timestamp + step` regardless of how
+    /// much wall-clock time actually elapsed between blocks, so sequences stay reproducible
+    /// whether blocks are mined manually or on the interval-mining timer.
+    interval: Option<u64>,
+}
+
+impl TimeManager {
+    /// Creates a new instance with the given genesis timestamp.
+    pub fn new(start_timestamp: u64) -> Self {
+        Self {
+            start: Instant::now(),
+            last_timestamp: start_timestamp,
+            next_exact_timestamp: None,
+            interval: None,
+        }
+    }
+
+    /// Returns the timestamp that should be used for the next mined block, and records it as the
+    /// new `last_timestamp`.
+    pub fn next_timestamp(&mut self) -> u64 {
+        let next = if let Some(timestamp) = self.next_exact_timestamp.take() {
+            timestamp
+        } else if let Some(interval) = self.interval {
+            self.last_timestamp.saturating_add(interval)
+        } else {
+            let elapsed = self.start.elapsed().as_secs();
+            self.last_timestamp.max(utc_now().as_secs()).max(self.last_timestamp + elapsed.min(1))
+        };
+
+        // Timestamps must never go backwards; equal timestamps between consecutive blocks are
+        // explicitly allowed.
+        self.last_timestamp = next.max(self.last_timestamp);
+        self.last_timestamp
+    }
+
+    /// Sets the exact timestamp to use for the next block.
+    ///
+    /// Only rejects timestamps that are strictly less than the last mined block's timestamp;
+    /// a timestamp equal to the last one is accepted so that two blocks can share a timestamp.
+    pub fn set_next_block_timestamp(&mut self, timestamp: u64) -> Result<(), TimeError> {
+        if timestamp < self.last_timestamp {
+            return Err(TimeError::TimestampError(format!(
+                "Timestamp error: {timestamp} is lower than previous block's timestamp {}",
+                self.last_timestamp
+            )))
+        }
+        self.next_exact_timestamp = Some(timestamp);
+        Ok(())
+    }
+
+    /// Sets a fixed interval (in seconds) that subsequent blocks' timestamps advance by, instead
+    /// of being derived from the wall clock. Passing `0` makes every subsequently mined block
+    /// report the same timestamp as its predecessor.
+    pub fn set_block_timestamp_interval(&mut self, interval: u64) {
+        self.interval = Some(interval);
+    }
+
+    /// Removes a previously set block timestamp interval, reverting to wall-clock timestamps.
+    pub fn remove_block_timestamp_interval(&mut self) -> bool {
+        self.interval.take().is_some()
+    }
+
+    /// Enables deterministic block-time step mode: every subsequently mined block's timestamp is
+    /// computed as `last_timestamp + step`, independent of wall-clock time. This is the same
+    /// underlying mechanism as [`Self::set_block_timestamp_interval`] (a `step` of `0` reproduces
+    /// flat timestamps), exposed under its own name because it's meant to be a permanent
+    /// replacement for clock-derived timestamps rather than a one-off flattening of two blocks.
+    pub fn set_block_time_step(&mut self, step: u64) {
+        self.interval = Some(step);
+    }
+
+    /// Returns whether the time manager is currently in step mode (either via
+    /// [`Self::set_block_timestamp_interval`] or [`Self::set_block_time_step`]).
+    pub fn is_step_mode(&self) -> bool {
+        self.interval.is_some()
+    }
+
+    /// Returns the timestamp of the last mined block.
+    pub fn last_timestamp(&self) -> u64 {
+        self.last_timestamp
+    }
+}
+
+/// Errors that can occur while manipulating the node's timestamps.
+#[derive(Debug, thiserror::Error)]
+pub enum TimeError {
+    #[error("{0}")]
+    TimestampError(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_only_strictly_decreasing_timestamps() {
+        let mut time = TimeManager::new(1000);
+        assert!(time.set_next_block_timestamp(1000).is_ok());
+        assert_eq!(time.next_timestamp(), 1000);
+
+        // Equal to the previous timestamp is allowed.
+        assert!(time.set_next_block_timestamp(1000).is_ok());
+        assert_eq!(time.next_timestamp(), 1000);
+
+        // Strictly less than the previous timestamp is rejected.
+        assert!(time.set_next_block_timestamp(999).is_err());
+    }
+}
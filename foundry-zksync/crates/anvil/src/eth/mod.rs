@@ -0,0 +1,30 @@
+pub mod api;
+pub mod backend;
+pub mod pubsub;
+
+use backend::{notifications::NewBlockNotification, Backend};
+use futures::Stream;
+use std::sync::Arc;
+
+/// Handle through which the JSON-RPC server dispatches `eth_*`/`anvil_*` requests.
+///
+/// Only the `backend` handle needed by the timestamp/beacon-slot RPCs in [`api`] and the
+/// `newHeads` subscription stream in [`pubsub`] is declared here; the request-dispatch table and
+/// the rest of `EthApi`'s surface live alongside this struct and aren't part of this reduced
+/// checkout.
+#[derive(Clone)]
+pub struct EthApi {
+    pub(crate) backend: Arc<Backend>,
+}
+
+impl EthApi {
+    pub fn new(backend: Arc<Backend>) -> Self {
+        Self { backend }
+    }
+
+    /// Opens a `newHeads`-style stream of newly sealed blocks, backed by the backend's shared
+    /// notification hub instead of a per-subscription poll.
+    pub fn new_heads_stream(&self) -> impl Stream<Item = NewBlockNotification> {
+        pubsub::new_heads_stream(&self.backend.notifications)
+    }
+}
@@ -0,0 +1,19 @@
+//! `eth_subscribe` support, backed by [`crate::eth::backend::notifications::BlockNotifications`]
+//! instead of a per-subscription polling task.
+
+use crate::eth::backend::notifications::NewBlockNotification;
+use futures::{Stream, StreamExt};
+use tokio_stream::wrappers::BroadcastStream;
+
+/// A `newHeads` subscription stream.
+///
+/// Previously, each subscription spawned its own task that repeatedly called
+/// `eth_getBlockByNumber`/`eth_blockNumber` on a timer, which both wasted cycles and meant
+/// subscribers learned about a new block anywhere up to one poll interval late. Now every
+/// subscription is simply a receiver on the backend's shared broadcast channel, so the first
+/// `.next()` after a block is sealed resolves immediately.
+pub fn new_heads_stream(
+    notifications: &crate::eth::backend::notifications::BlockNotifications,
+) -> impl Stream<Item = NewBlockNotification> {
+    BroadcastStream::new(notifications.subscribe()).filter_map(|res| async move { res.ok() })
+}
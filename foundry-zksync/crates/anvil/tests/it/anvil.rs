@@ -1,39 +1,66 @@
 //! tests for anvil specific logic
 
-use crate::utils::ethers_http_provider;
+use crate::utils::{ethers_http_provider, ethers_ws_provider};
 use anvil::{spawn, NodeConfig};
-use ethers::{prelude::Middleware, types::Address};
+use ethers::{
+    prelude::{Middleware, StreamExt},
+    types::Address,
+};
 use foundry_common::types::ToAlloy;
+use std::time::Duration;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_can_change_mining_mode() {
     let (api, handle) = spawn(NodeConfig::test()).await;
     let provider = ethers_http_provider(&handle.http_endpoint());
+    let ws_provider = ethers_ws_provider(&handle.ws_endpoint());
 
     assert!(api.anvil_get_auto_mine().unwrap());
 
     let num = provider.get_block_number().await.unwrap();
     assert_eq!(num.as_u64(), 0);
 
+    // The block notifier the mining task fires on lets us await the next head deterministically
+    // instead of polling `get_block_number` on a sleep timer.
+    let mut new_heads = ws_provider.subscribe_blocks().await.unwrap();
+
     api.anvil_set_interval_mining(1).unwrap();
     assert!(!api.anvil_get_auto_mine().unwrap());
-    // changing the mining mode will instantly mine a new block
-    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-    let num = provider.get_block_number().await.unwrap();
-    assert_eq!(num.as_u64(), 0);
 
-    tokio::time::sleep(std::time::Duration::from_millis(700)).await;
+    let head = tokio::time::timeout(Duration::from_secs(5), new_heads.next())
+        .await
+        .expect("timed out waiting for interval-mined block")
+        .expect("notification stream ended unexpectedly");
+    assert_eq!(head.number.unwrap().as_u64(), 1);
+
     let num = provider.get_block_number().await.unwrap();
     assert_eq!(num.as_u64(), 1);
 
-    // assert that no block is mined when the interval is set to 0
+    // assert that no further block is mined when the interval is set to 0
     api.anvil_set_interval_mining(0).unwrap();
     assert!(!api.anvil_get_auto_mine().unwrap());
-    tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+    assert!(tokio::time::timeout(Duration::from_millis(500), new_heads.next()).await.is_err());
     let num = provider.get_block_number().await.unwrap();
     assert_eq!(num.as_u64(), 1);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_can_use_block_time_step() {
+    let (api, handle) = spawn(NodeConfig::test().with_block_time_step(12u64)).await;
+    let provider = ethers_http_provider(&handle.http_endpoint());
+
+    let genesis = provider.get_block(0).await.unwrap().unwrap().timestamp.as_u64();
+
+    // No sleeping: timestamps advance by exactly `step` regardless of wall-clock time.
+    api.mine_one().await;
+    let first = provider.get_block(1).await.unwrap().unwrap().timestamp.as_u64();
+    assert_eq!(first, genesis + 12);
+
+    api.mine_one().await;
+    let second = provider.get_block(2).await.unwrap().unwrap().timestamp.as_u64();
+    assert_eq!(second, genesis + 24);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn can_get_default_dev_keys() {
     let (_api, handle) = spawn(NodeConfig::test()).await;
@@ -50,6 +77,24 @@ async fn can_get_default_dev_keys() {
     assert_eq!(dev_accounts, accounts);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn can_get_proposer_duties_from_dev_accounts() {
+    let (api, handle) = spawn(NodeConfig::test().with_slot_mining(12, 32)).await;
+
+    let dev_accounts = handle.dev_accounts().collect::<Vec<_>>();
+
+    let epoch = api.anvil_get_current_epoch();
+    let duties = api.anvil_get_proposer_duties(epoch);
+
+    assert_eq!(duties.len(), 32);
+    for duty in &duties {
+        assert!(dev_accounts.contains(&duty.address));
+    }
+
+    // The assignment is deterministic: asking again returns the exact same schedule.
+    assert_eq!(duties, api.anvil_get_proposer_duties(epoch));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn can_set_empty_code() {
     let (api, _handle) = spawn(NodeConfig::test()).await;
@@ -76,3 +121,26 @@ async fn test_can_use_default_genesis_timestamp() {
 
     assert_ne!(0u64, provider.get_block(0).await.unwrap().unwrap().timestamp.as_u64());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_can_mine_blocks_with_identical_timestamps() {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let provider = ethers_http_provider(&handle.http_endpoint());
+
+    let timestamp = provider.get_block(0).await.unwrap().unwrap().timestamp.as_u64();
+
+    // Setting the next block's timestamp to the same value as the previous block is allowed: the
+    // invariant is monotonic *non-decreasing* timestamps, not strictly increasing ones.
+    api.anvil_set_next_block_timestamp(timestamp).unwrap();
+    api.mine_one().await;
+    let first = provider.get_block(1).await.unwrap().unwrap().timestamp.as_u64();
+    assert_eq!(first, timestamp);
+
+    api.anvil_set_next_block_timestamp(timestamp).unwrap();
+    api.mine_one().await;
+    let second = provider.get_block(2).await.unwrap().unwrap().timestamp.as_u64();
+    assert_eq!(second, timestamp);
+
+    // Going backwards in time is still rejected.
+    assert!(api.anvil_set_next_block_timestamp(timestamp - 1).is_err());
+}
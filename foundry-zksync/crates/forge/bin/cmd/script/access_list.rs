@@ -0,0 +1,104 @@
+use super::transaction::TransactionWithMetadata;
+use alloy_rpc_types::AccessList;
+use ethers_core::types::transaction::eip2718::TypedTransaction;
+use ethers_providers::Middleware;
+use eyre::{Result, WrapErr};
+use foundry_common::provider::ethers::RetryProvider;
+
+/// Calls `eth_createAccessList` for `tx` against `provider`, attaches the returned access list to
+/// the transaction, and re-estimates gas with the list applied, keeping whichever gas value (with
+/// vs. without the access list) is lower.
+///
+/// EIP-2930 access lists warm storage slots ahead of execution; for transactions that touch many
+/// cold slots this can be cheaper than paying the cold-access surcharge during execution, but for
+/// simple transfers it's pure overhead, so we only keep it when it actually pays off.
+pub async fn fill_access_list(
+    tx: &mut TransactionWithMetadata,
+    provider: &RetryProvider,
+) -> Result<()> {
+    let typed_tx = tx.typed_tx_mut();
+    let original_gas = *typed_tx.gas().expect("gas is set by simulation.");
+
+    let access_list_with_gas_used = provider
+        .create_access_list(typed_tx, None)
+        .await
+        .wrap_err("Failed to create access list")?;
+
+    let estimated_with_list = ethers_core::types::U256::from(access_list_with_gas_used.gas_used.as_u64());
+
+    if estimated_with_list < original_gas {
+        let access_list: AccessList = access_list_with_gas_used
+            .access_list
+            .items
+            .into_iter()
+            .map(|item| alloy_rpc_types::AccessListItem {
+                address: item.address.into(),
+                storage_keys: item.storage_keys.into_iter().map(Into::into).collect(),
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        let attached = match typed_tx {
+            TypedTransaction::Eip1559(ref mut inner) => {
+                inner.access_list = access_list_with_gas_used.access_list;
+                true
+            }
+            TypedTransaction::Legacy(_) => {
+                // Legacy transactions cannot carry an access list; callers that opted into
+                // `--access-list` are responsible for upgrading these to EIP-2930 beforehand.
+                // The discounted gas estimate only applies once the list is actually attached, so
+                // this transaction must keep its original (undiscounted) gas limit.
+                false
+            }
+            TypedTransaction::Eip2930(ref mut inner) => {
+                inner.access_list = access_list_with_gas_used.access_list;
+                true
+            }
+        };
+
+        if attached {
+            tx.set_access_list(access_list);
+            typed_tx.set_gas(estimated_with_list.min(original_gas));
+        } else {
+            typed_tx.set_gas(original_gas);
+        }
+    } else {
+        typed_tx.set_gas(original_gas);
+    }
+
+    Ok(())
+}
+
+/// Like [`fill_access_list`], but for transactions that are about to be signed manually (the
+/// `--private-keys`/`--ledger`/etc. path in `broadcast`, as opposed to a configured
+/// [`ethers_signers`] wallet going through `fill_transaction`).
+///
+/// Legacy transactions are upgraded to EIP-2930 in place so the returned access list has
+/// somewhere to live; EIP-1559 and EIP-2930 transactions keep their type.
+pub async fn fill_access_list_before_signing(
+    tx: &mut TypedTransaction,
+    provider: &RetryProvider,
+) -> Result<()> {
+    let access_list_with_gas_used =
+        provider.create_access_list(tx, None).await.wrap_err("Failed to create access list")?;
+
+    if let TypedTransaction::Legacy(inner) = tx {
+        *tx = TypedTransaction::Eip2930(ethers_core::types::transaction::eip2930::Eip2930TransactionRequest {
+            tx: inner.clone(),
+            access_list: access_list_with_gas_used.access_list,
+        });
+        return Ok(())
+    }
+
+    match tx {
+        TypedTransaction::Eip1559(inner) => {
+            inner.access_list = access_list_with_gas_used.access_list;
+        }
+        TypedTransaction::Eip2930(inner) => {
+            inner.access_list = access_list_with_gas_used.access_list;
+        }
+        TypedTransaction::Legacy(_) => unreachable!("handled above"),
+    }
+
+    Ok(())
+}
@@ -1,5 +1,18 @@
 use super::{
+    access_list::{fill_access_list, fill_access_list_before_signing},
+    create2::{ensure_create2_deployer, route_through_create2_deployer},
+    gas_oracle::GasPriceOracle,
+    l2_fees::{estimate_op_stack_l1_fee, is_arbitrum},
+    fee::{estimate_eip1559_fees_percentile, DEFAULT_FEE_HISTORY_BLOCKS, DEFAULT_FEE_PERCENTILE},
     multi::MultiChainSequence,
+    nonce_manager::{is_nonce_rejection, SenderNonceManager},
+    user_op::{
+        entry_point_nonce, estimated_user_op_total_gas, pack_call_data, send_user_operation,
+        user_op_hash, UserOperation, DEFAULT_PRE_VERIFICATION_GAS, DEFAULT_VERIFICATION_GAS_LIMIT,
+        ENTRY_POINT_V06,
+    },
+    resubmit::{resubmit_if_stuck, MIN_REPLACEMENT_BUMP_PERCENT},
+    verify_backend::{backends_for, verify_with_all_backends},
     providers::ProvidersManager,
     receipts::clear_pendings,
     sequence::ScriptSequence,
@@ -48,6 +61,7 @@ impl ScriptArgs {
         deployment_sequence: &mut ScriptSequence,
         fork_url: &str,
         signers: &HashMap<Address, WalletSigner>,
+        gas_oracle: &GasPriceOracle,
     ) -> Result<()> {
         let provider = Arc::new(try_get_http_provider(fork_url)?);
         let already_broadcasted = deployment_sequence.receipts.len();
@@ -100,26 +114,49 @@ impl ScriptArgs {
 
                 let chain = provider.get_chainid().await?.as_u64();
 
-                (SendTransactionsKind::Raw(signers), chain)
+                if self.account.is_some() {
+                    (SendTransactionsKind::AccountAbstraction(signers), chain)
+                } else {
+                    (SendTransactionsKind::Raw(signers), chain)
+                }
             };
 
-            // We only wait for a transaction receipt before sending the next transaction, if there
-            // is more than one signer. There would be no way of assuring their order
-            // otherwise. Or if the chain does not support batched transactions (eg. Arbitrum).
-            let sequential_broadcast =
-                send_kind.signers_count() != 1 || self.slow || !has_batch_support(chain);
+            // We only wait for a transaction receipt before sending the next transaction if the
+            // chain does not support batched transactions (eg. Arbitrum), or the user passed
+            // `--slow`. Multiple signers no longer force full seriality: each sender's nonces are
+            // assigned locally by `SenderNonceManager` in submission order, so transactions from
+            // *different* senders can be broadcast concurrently while transactions from the
+            // *same* sender keep their relative order and incrementing nonce.
+            let sequential_broadcast = self.slow || !has_batch_support(chain);
 
             // Make a one-time gas price estimation
             let (gas_price, eip1559_fees) = {
                 match deployment_sequence.transactions.front().unwrap().typed_tx() {
                     TypedTransaction::Eip1559(_) => {
-                        let fees = estimate_eip1559_fees(&provider, Some(chain))
+                        let fees = if let Some(percentile) = self.fee_percentile {
+                            let estimate = estimate_eip1559_fees_percentile(
+                                &provider,
+                                DEFAULT_FEE_HISTORY_BLOCKS,
+                                percentile,
+                            )
                             .await
-                            .wrap_err("Failed to estimate EIP1559 fees. This chain might not support EIP1559, try adding --legacy to your command.")?;
+                            .wrap_err("Failed to estimate EIP1559 fees from fee history. This chain might not support EIP1559, try adding --legacy to your command.")?;
+                            (estimate.max_fee, estimate.priority_fee)
+                        } else {
+                            estimate_eip1559_fees(&provider, Some(chain))
+                                .await
+                                .wrap_err("Failed to estimate EIP1559 fees. This chain might not support EIP1559, try adding --legacy to your command.")?
+                        };
 
                         (None, Some(fees))
                     }
-                    _ => (provider.get_gas_price().await.ok(), None),
+                    _ => {
+                        let price = match gas_oracle.recommended_price(&fork_url.to_string(), &provider).await {
+                            Some(price) => Some(price.to_ethers()),
+                            None => provider.get_gas_price().await.ok(),
+                        };
+                        (price, None)
+                    }
                 }
             };
 
@@ -173,6 +210,8 @@ impl ScriptArgs {
             // cannot handle more than that.
             let batch_size = 100;
             let mut index = 0;
+            let nonces = Arc::new(tokio::sync::Mutex::new(SenderNonceManager::new()));
+            let bump_percent = self.resubmit_bump_percent.unwrap_or(MIN_REPLACEMENT_BUMP_PERCENT);
 
             for (batch_number, batch) in sequence.chunks(batch_size).map(|f| f.to_vec()).enumerate()
             {
@@ -184,35 +223,89 @@ impl ScriptArgs {
                     batch_number * batch_size + min(batch_size, batch.len()) - 1
                 ))?;
                 for (tx, zk, kind, is_fixed_gas_limit) in batch.into_iter() {
+                    let resubmit_tx = tx.clone();
+                    let resubmit_signer =
+                        if let SendTransactionKind::Raw(signer) = &kind { Some(*signer) } else { None };
+
                     let tx_hash = self.send_transaction(
                         provider.clone(),
                         tx,
                         zk,
                         kind,
-                        sequential_broadcast,
+                        nonces.clone(),
                         fork_url,
                         is_fixed_gas_limit,
                     );
 
                     if sequential_broadcast {
-                        let tx_hash = tx_hash.await?;
+                        let mut tx_hash = tx_hash.await?;
                         deployment_sequence.add_pending(index, tx_hash);
 
+                        if let (Some(resubmit_after), Some(signer)) =
+                            (self.resubmit_after, resubmit_signer)
+                        {
+                            let (new_hash, replaced_from) = resubmit_if_stuck(
+                                provider.clone(),
+                                signer,
+                                resubmit_tx,
+                                tx_hash,
+                                std::time::Duration::from_secs(resubmit_after),
+                                bump_percent,
+                            )
+                            .await?;
+                            if let Some(original) = replaced_from {
+                                deployment_sequence.add_pending_replacement(original, new_hash);
+                            }
+                            tx_hash = new_hash;
+                        }
+
                         update_progress!(pb, (index + already_broadcasted));
                         index += 1;
 
                         clear_pendings(provider.clone(), deployment_sequence, Some(vec![tx_hash]))
                             .await?;
                     } else {
-                        pending_transactions.push(tx_hash);
+                        let provider = provider.clone();
+                        let resubmit_after = self.resubmit_after;
+                        pending_transactions.push(async move {
+                            let tx_hash = tx_hash.await?;
+
+                            if let (Some(resubmit_after), Some(signer)) =
+                                (resubmit_after, resubmit_signer)
+                            {
+                                resubmit_if_stuck(
+                                    provider,
+                                    signer,
+                                    resubmit_tx,
+                                    tx_hash,
+                                    std::time::Duration::from_secs(resubmit_after),
+                                    bump_percent,
+                                )
+                                .await
+                            } else {
+                                Ok((tx_hash, None))
+                            }
+                        });
                     }
                 }
 
                 if !pending_transactions.is_empty() {
-                    let mut buffer = futures::stream::iter(pending_transactions).buffered(7);
-
-                    while let Some(tx_hash) = buffer.next().await {
-                        let tx_hash = tx_hash?;
+                    // Caps how many sends are in flight at once; transactions from the same
+                    // sender still get sequential nonces from `SenderNonceManager` above, so
+                    // raising this mostly shortens wall-clock time for scripts with many
+                    // different senders or a batch-supporting single-sender chain.
+                    //
+                    // `--resubmit-after` rides along inside each of these futures (see above), so
+                    // a stuck transaction on this path gets bumped and replaced the same way a
+                    // sequential one does, instead of silently never resubmitting.
+                    let in_flight = self.batch_size.unwrap_or(7) as usize;
+                    let mut buffer = futures::stream::iter(pending_transactions).buffered(in_flight);
+
+                    while let Some(result) = buffer.next().await {
+                        let (tx_hash, replaced_from) = result?;
+                        if let Some(original) = replaced_from {
+                            deployment_sequence.add_pending_replacement(original, tx_hash);
+                        }
                         deployment_sequence.add_pending(index, tx_hash);
 
                         update_progress!(pb, (index + already_broadcasted));
@@ -262,28 +355,67 @@ impl ScriptArgs {
     async fn send_transaction(
         &self,
         provider: Arc<RetryProvider>,
-        mut tx: TypedTransaction,
+        tx: TypedTransaction,
         zk: Option<ZkTransaction>,
         kind: SendTransactionKind<'_>,
-        sequential_broadcast: bool,
+        nonces: Arc<tokio::sync::Mutex<SenderNonceManager>>,
         fork_url: &str,
         is_fixed_gas_limit: bool,
     ) -> Result<TxHash> {
-        let from = tx.from().expect("no sender");
-
-        if sequential_broadcast {
-            let nonce = forge::next_nonce((*from).to_alloy(), fork_url, None)
-                .await
-                .map_err(|_| eyre::eyre!("Not able to query the EOA nonce."))?;
+        let from = (*tx.from().expect("no sender")).to_alloy();
+
+        // Post-assignment invariant: the nonce this transaction was simulated with must match
+        // what `SenderNonceManager` would hand out next for this sender. The manager fetches each
+        // sender's starting nonce from the chain exactly once, so this check doesn't require a
+        // fresh RPC round-trip per transaction the way re-querying `eth_getTransactionCount`
+        // here every time would.
+        let expected_nonce = nonces.lock().await.next(from, fork_url).await?;
+        let tx_nonce = tx.nonce().expect("no nonce");
+        if let Ok(tx_nonce) = u64::try_from(tx_nonce.to_alloy()) {
+            if expected_nonce != tx_nonce {
+                bail!("EOA nonce changed unexpectedly while sending transactions. Expected {tx_nonce} got {expected_nonce} from provider.")
+            }
+        }
 
-            let tx_nonce = tx.nonce().expect("no nonce");
-            if let Ok(tx_nonce) = u64::try_from(tx_nonce.to_alloy()) {
-                if nonce != tx_nonce {
-                    bail!("EOA nonce changed unexpectedly while sending transactions. Expected {tx_nonce} got {nonce} from provider.")
-                }
+        // Kept around in case the first attempt is rejected for a stale nonce and needs to be
+        // resent with a corrected one.
+        let retry_tx = tx.clone();
+        let retry_zk = zk.clone();
+        let retry_kind = kind.clone();
+
+        let result = self.try_send_transaction(&provider, tx, zk, kind, is_fixed_gas_limit).await;
+
+        // A rejected nonce means our locally tracked nonce for this sender has drifted from
+        // what the node actually has (e.g. a transaction from a previous `--resume` run already
+        // landed). Resync against the chain and resubmit this transaction with the corrected
+        // nonce, instead of just fixing the count up for transactions that come after it.
+        if let Err(err) = &result {
+            if is_nonce_rejection(err) {
+                let mut nonces = nonces.lock().await;
+                nonces.resync(from, fork_url).await?;
+                let corrected_nonce = nonces.next(from, fork_url).await?;
+                drop(nonces);
+
+                let mut retry_tx = retry_tx;
+                retry_tx.set_nonce(corrected_nonce);
+                return self
+                    .try_send_transaction(&provider, retry_tx, retry_zk, retry_kind, is_fixed_gas_limit)
+                    .await
             }
         }
 
+        result
+    }
+
+    /// Submits `tx` via whichever [`SendTransactionKind`] was resolved for its sender.
+    async fn try_send_transaction(
+        &self,
+        provider: &Arc<RetryProvider>,
+        mut tx: TypedTransaction,
+        zk: Option<ZkTransaction>,
+        kind: SendTransactionKind<'_>,
+        is_fixed_gas_limit: bool,
+    ) -> Result<TxHash> {
         match kind {
             SendTransactionKind::Unlocked(addr) => {
                 debug!("sending transaction from unlocked account {:?}: {:?}", addr, tx);
@@ -294,15 +426,17 @@ impl ScriptArgs {
                     (has_different_gas_calc(provider.get_chainid().await?.as_u64()) ||
                         self.skip_simulation)
                 {
-                    self.estimate_gas(&mut tx, &provider).await?;
+                    self.estimate_gas(&mut tx, provider).await?;
                 }
 
                 // Submit the transaction
-                let pending = provider.send_transaction(tx, None).await?;
-
-                Ok(pending.tx_hash().to_alloy())
+                provider.send_transaction(tx, None).await.map(|pending| pending.tx_hash().to_alloy())
+                    .wrap_err("Failed to send transaction")
+            }
+            SendTransactionKind::Raw(signer) => self.broadcast(provider.clone(), signer, tx, zk).await,
+            SendTransactionKind::AccountAbstraction(signer) => {
+                self.broadcast_user_operation(signer, tx).await
             }
-            SendTransactionKind::Raw(signer) => self.broadcast(provider, signer, tx, zk).await,
         }
     }
 
@@ -327,6 +461,8 @@ impl ScriptArgs {
             if !script_config.missing_rpc {
                 trace!(target: "script", "creating deployments");
 
+                let gas_oracle = GasPriceOracle::new(self.gas_price_percentile.unwrap_or(50.0));
+
                 let mut deployments = self
                     .create_script_sequences(
                         txs,
@@ -335,6 +471,7 @@ impl ScriptArgs {
                         decoder,
                         &verify.known_contracts,
                         dual_compiled_contracts,
+                        &gas_oracle,
                     )
                     .await?;
 
@@ -366,6 +503,7 @@ impl ScriptArgs {
                         libraries,
                         verify,
                         signers,
+                        &gas_oracle,
                     )
                     .await?;
                 }
@@ -388,6 +526,7 @@ impl ScriptArgs {
         libraries: Libraries,
         verify: VerifyBundle,
         signers: &HashMap<Address, WalletSigner>,
+        gas_oracle: &GasPriceOracle,
     ) -> Result<()> {
         trace!(target: "script", "broadcasting single chain deployment");
 
@@ -399,11 +538,49 @@ impl ScriptArgs {
 
         deployment_sequence.add_libraries(libraries);
 
-        self.send_transactions(deployment_sequence, &rpc, signers).await?;
+        self.send_transactions(deployment_sequence, &rpc, signers, gas_oracle).await?;
 
         if self.verify {
-            return deployment_sequence.verify_contracts(&script_config.config, verify).await;
+            deployment_sequence.verify_contracts(&script_config.config, verify).await?;
+        }
+
+        if !self.verifiers.is_empty() {
+            self.verify_with_additional_backends(deployment_sequence, &script_config.config)
+                .await?;
         }
+
+        Ok(())
+    }
+
+    /// Runs every configured non-Etherscan verification backend (Sourcify, Blockscout, ...)
+    /// against every contract created in `deployment_sequence`, selecting which backends apply by
+    /// chain id. A backend failing to verify a contract is recorded and does not prevent the
+    /// other backends, or other contracts, from being verified.
+    async fn verify_with_additional_backends(
+        &self,
+        deployment_sequence: &ScriptSequence,
+        config: &Config,
+    ) -> Result<()> {
+        let chain_id = config.chain.map(|c| c.id()).unwrap_or_default();
+        let backends = backends_for(&self.verifiers);
+
+        for (contract, address, constructor_args) in deployment_sequence.verifiable_contracts() {
+            let results =
+                verify_with_all_backends(&backends, chain_id, &contract, address, &constructor_args)
+                    .await;
+            for (backend, outcome) in results {
+                if outcome.success {
+                    shell::println(format!("✅ Verified {} on {backend}", contract.identifier()))?;
+                } else {
+                    shell::println(format!(
+                        "⚠️ Failed to verify {} on {backend}: {}",
+                        contract.identifier(),
+                        outcome.message
+                    ))?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -420,6 +597,7 @@ impl ScriptArgs {
         decoder: &CallTraceDecoder,
         known_contracts: &ContractsByArtifact,
         dual_compiled_contracts: Option<DualCompiledContracts>,
+        gas_oracle: &GasPriceOracle,
     ) -> Result<Vec<ScriptSequence>> {
         if !txs.is_empty() {
             let gas_filled_txs = self
@@ -440,6 +618,7 @@ impl ScriptArgs {
                     &script_config.target_contract().clone(),
                     &mut script_config.config,
                     returns,
+                    gas_oracle,
                 )
                 .await;
         } else if self.broadcast {
@@ -497,12 +676,14 @@ impl ScriptArgs {
         target: &ArtifactId,
         config: &mut Config,
         returns: HashMap<String, NestedValue>,
+        gas_oracle: &GasPriceOracle,
     ) -> Result<Vec<ScriptSequence>> {
         // User might be using both "in-code" forks and `--fork-url`.
         let last_rpc = &transactions.back().expect("exists; qed").rpc;
         let is_multi_deployment = transactions.iter().any(|tx| &tx.rpc != last_rpc);
 
         let mut total_gas_per_rpc: HashMap<RpcUrl, U256> = HashMap::new();
+        let mut total_l1_fee_per_rpc: HashMap<RpcUrl, U256> = HashMap::new();
 
         // Batches sequence of transactions from different rpcs.
         let mut new_sequence = VecDeque::new();
@@ -534,34 +715,80 @@ impl ScriptArgs {
             tx.change_type(provider_info.is_legacy);
             tx.transaction.set_chain_id(provider_info.chain);
 
-            if !self.skip_simulation {
-                let typed_tx = tx.typed_tx_mut();
+            let mut routed_through_create2 = false;
+            if self.create2 && tx.typed_tx().to().is_none() {
+                ensure_create2_deployer(&provider_info.provider)
+                    .await
+                    .wrap_err("Failed to ensure the CREATE2 deployer is deployed")?;
 
-                if has_different_gas_calc(provider_info.chain) {
-                    trace!("estimating with different gas calculation");
-                    let gas = *typed_tx.gas().expect("gas is set by simulation.");
+                let salt = self.create2_salt.unwrap_or_default();
+                route_through_create2_deployer(&mut tx, salt)
+                    .wrap_err("Failed to route contract creation through the CREATE2 deployer")?;
+                routed_through_create2 = true;
+            }
 
-                    // We are trying to show the user an estimation of the total gas usage.
-                    //
-                    // However, some transactions might depend on previous ones. For
-                    // example, tx1 might deploy a contract that tx2 uses. That
-                    // will result in the following `estimate_gas` call to fail,
-                    // since tx1 hasn't been broadcasted yet.
-                    //
-                    // Not exiting here will not be a problem when actually broadcasting, because
-                    // for chains where `has_different_gas_calc` returns true,
-                    // we await each transaction before broadcasting the next
-                    // one.
-                    if let Err(err) = self.estimate_gas(typed_tx, &provider_info.provider).await {
-                        trace!("gas estimation failed: {err}");
-
-                        // Restore gas value, since `estimate_gas` will remove it.
-                        typed_tx.set_gas(gas);
+            if !self.skip_simulation {
+                {
+                    let typed_tx = tx.typed_tx_mut();
+
+                    // Routing through the CREATE2 deployer rewrites `to` and `data` to go through
+                    // an extra contract call, so the gas estimate taken during simulation no
+                    // longer reflects the actual cost - re-estimate regardless of whether this
+                    // chain otherwise needs it.
+                    if has_different_gas_calc(provider_info.chain) || routed_through_create2 {
+                        trace!("estimating with different gas calculation");
+                        let gas = *typed_tx.gas().expect("gas is set by simulation.");
+
+                        // We are trying to show the user an estimation of the total gas usage.
+                        //
+                        // However, some transactions might depend on previous ones. For
+                        // example, tx1 might deploy a contract that tx2 uses. That
+                        // will result in the following `estimate_gas` call to fail,
+                        // since tx1 hasn't been broadcasted yet.
+                        //
+                        // Not exiting here will not be a problem when actually broadcasting,
+                        // because for chains where `has_different_gas_calc` returns true,
+                        // we await each transaction before broadcasting the next
+                        // one.
+                        if let Err(err) = self.estimate_gas(typed_tx, &provider_info.provider).await {
+                            trace!("gas estimation failed: {err}");
+
+                            // Restore gas value, since `estimate_gas` will remove it.
+                            typed_tx.set_gas(gas);
+                        }
+                    }
+                }
+
+                if self.access_list {
+                    if let Err(err) = fill_access_list(&mut tx, &provider_info.provider).await {
+                        trace!("access list generation failed: {err}");
                     }
                 }
 
+                if is_arbitrum(provider_info.chain) {
+                    trace!("L1 data fee already folded into the gas estimate on Arbitrum");
+                } else if let Some(l1_fee) =
+                    estimate_op_stack_l1_fee(&provider_info.provider, provider_info.chain, tx.typed_tx())
+                        .await
+                {
+                    *total_l1_fee_per_rpc.entry(tx_rpc.clone()).or_insert(U256::ZERO) += l1_fee;
+                }
+
+                let typed_tx = tx.typed_tx_mut();
+                let call_gas_limit = (*typed_tx.gas().expect("gas is set")).to_alloy();
+
+                // In `--account` mode every transaction is eventually repackaged into a
+                // `UserOperation` and billed for `preVerificationGas + verificationGasLimit +
+                // callGasLimit`, not just its own gas limit - account for that here so the
+                // summary isn't quoting a cost the user will never actually pay.
+                let tx_gas = if self.account.is_some() {
+                    estimated_user_op_total_gas(call_gas_limit)
+                } else {
+                    call_gas_limit
+                };
+
                 let total_gas = total_gas_per_rpc.entry(tx_rpc.clone()).or_insert(U256::ZERO);
-                *total_gas += (*typed_tx.gas().expect("gas is set")).to_alloy();
+                *total_gas += tx_gas;
             }
 
             new_sequence.push_back(tx);
@@ -599,8 +826,25 @@ impl ScriptArgs {
 
                 // We don't store it in the transactions, since we want the most updated value.
                 // Right before broadcasting.
+                let eip1559_estimate = if self.with_gas_price.is_none() && !provider_info.is_legacy
+                {
+                    estimate_eip1559_fees_percentile(
+                        &provider_info.provider,
+                        DEFAULT_FEE_HISTORY_BLOCKS,
+                        self.fee_percentile.unwrap_or(DEFAULT_FEE_PERCENTILE),
+                    )
+                    .await
+                    .ok()
+                } else {
+                    None
+                };
+
                 let per_gas = if let Some(gas_price) = self.with_gas_price {
                     gas_price
+                } else if let Some(estimate) = eip1559_estimate {
+                    estimate.max_fee
+                } else if let Some(price) = gas_oracle.recommended_price(&rpc, &provider_info.provider).await {
+                    price
                 } else {
                     provider_info.gas_price()?
                 };
@@ -608,17 +852,47 @@ impl ScriptArgs {
                 shell::println("\n==========================")?;
                 shell::println(format!("\nChain {}", provider_info.chain))?;
 
-                shell::println(format!(
-                    "\nEstimated gas price: {} gwei",
-                    format_units(per_gas, 9)
-                        .unwrap_or_else(|_| "[Could not calculate]".to_string())
-                        .trim_end_matches('0')
-                        .trim_end_matches('.')
-                ))?;
+                if let Some(estimate) = eip1559_estimate {
+                    shell::println(format!(
+                        "\nEstimated base fee: {} gwei",
+                        format_units(estimate.base_fee, 9)
+                            .unwrap_or_else(|_| "[Could not calculate]".to_string())
+                            .trim_end_matches('0')
+                            .trim_end_matches('.')
+                    ))?;
+                    shell::println(format!(
+                        "\nEstimated priority fee: {} gwei",
+                        format_units(estimate.priority_fee, 9)
+                            .unwrap_or_else(|_| "[Could not calculate]".to_string())
+                            .trim_end_matches('0')
+                            .trim_end_matches('.')
+                    ))?;
+                } else {
+                    shell::println(format!(
+                        "\nEstimated gas price: {} gwei",
+                        format_units(per_gas, 9)
+                            .unwrap_or_else(|_| "[Could not calculate]".to_string())
+                            .trim_end_matches('0')
+                            .trim_end_matches('.')
+                    ))?;
+                }
                 shell::println(format!("\nEstimated total gas used for script: {total_gas}"))?;
+
+                let l1_data_fee = total_l1_fee_per_rpc.get(&rpc).copied().unwrap_or_default();
+                let l2_execution_cost = total_gas.saturating_mul(per_gas);
+
+                if !l1_data_fee.is_zero() {
+                    shell::println(format!(
+                        "\nEstimated L1 data fee: {} ETH",
+                        format_units(l1_data_fee, 18)
+                            .unwrap_or_else(|_| "[Could not calculate]".to_string())
+                            .trim_end_matches('0')
+                    ))?;
+                }
+
                 shell::println(format!(
                     "\nEstimated amount required: {} ETH",
-                    format_units(total_gas.saturating_mul(per_gas), 18)
+                    format_units(l2_execution_cost.saturating_add(l1_data_fee), 18)
                         .unwrap_or_else(|_| "[Could not calculate]".to_string())
                         .trim_end_matches('0')
                 ))?;
@@ -685,7 +959,12 @@ impl ScriptArgs {
             [&[EIP712_TX_TYPE], encoded_rlp].concat().into()
         } else {
             // Signing manually so we skip `fill_transaction` and its `eth_createAccessList`
-            // request.
+            // request by default; opted back in via `--access-list`.
+            if self.access_list {
+                fill_access_list_before_signing(&mut legacy_or_1559, &provider).await?;
+                self.estimate_gas(&mut legacy_or_1559, &provider).await?;
+            }
+
             let signature = signer
                 .sign_transaction(&legacy_or_1559)
                 .await
@@ -699,6 +978,64 @@ impl ScriptArgs {
         Ok(pending.tx_hash().to_alloy())
     }
 
+    /// Routes a transaction through an ERC-4337 smart-contract account instead of signing and
+    /// broadcasting it as a plain EOA transaction.
+    ///
+    /// Packs `(to, value, data)` into the account's `execute` calldata, fetches the account's
+    /// nonce from the `EntryPoint`, signs the resulting `userOpHash` with `signer` (the account
+    /// owner's key), and submits to `--bundler-url`, polling for the transaction hash the bundler
+    /// eventually includes it under so the existing receipt/`--resume` machinery can pick it up.
+    async fn broadcast_user_operation(
+        &self,
+        signer: &WalletSigner,
+        tx: TypedTransaction,
+    ) -> Result<TxHash> {
+        let bundler_url =
+            self.bundler_url.as_ref().wrap_err("--bundler-url is required when using --account")?;
+        let bundler = try_get_http_provider(bundler_url)?;
+        let entry_point = self.entry_point.unwrap_or(ENTRY_POINT_V06);
+
+        let sender = (*tx.from().expect("no sender")).to_alloy();
+        let to = tx.to().and_then(|to| to.as_address()).map(|addr| (*addr).to_alloy()).unwrap_or_default();
+        let value = tx.value().copied().unwrap_or_default().to_alloy();
+        let call_data =
+            pack_call_data(to, value, &alloy_primitives::Bytes::from(tx.data().cloned().unwrap_or_default().to_vec()));
+
+        let (max_fee_per_gas, max_priority_fee_per_gas) = match &tx {
+            TypedTransaction::Eip1559(inner) => (
+                inner.max_fee_per_gas.unwrap_or_default().to_alloy(),
+                inner.max_priority_fee_per_gas.unwrap_or_default().to_alloy(),
+            ),
+            _ => {
+                let gas_price = tx.gas_price().unwrap_or_default().to_alloy();
+                (gas_price, gas_price)
+            }
+        };
+
+        let mut op = UserOperation {
+            sender,
+            nonce: entry_point_nonce(&bundler, entry_point, sender).await?,
+            init_code: alloy_primitives::Bytes::new(),
+            call_data,
+            call_gas_limit: tx.gas().copied().unwrap_or_default().to_alloy(),
+            verification_gas_limit: U256::from(DEFAULT_VERIFICATION_GAS_LIMIT),
+            pre_verification_gas: U256::from(DEFAULT_PRE_VERIFICATION_GAS),
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            paymaster_and_data: alloy_primitives::Bytes::new(),
+            signature: alloy_primitives::Bytes::new(),
+        };
+
+        let op_hash = user_op_hash(&bundler, entry_point, &op).await?;
+        let signature = signer
+            .sign_message(op_hash.as_slice())
+            .await
+            .wrap_err("Failed to sign user operation hash")?;
+        op.signature = alloy_primitives::Bytes::from(signature.to_vec());
+
+        send_user_operation(&bundler, entry_point, &op).await
+    }
+
     async fn estimate_gas<T>(&self, tx: &mut TypedTransaction, provider: &Provider<T>) -> Result<()>
     where
         T: JsonRpcClient,
@@ -724,6 +1061,8 @@ impl ScriptArgs {
 enum SendTransactionKind<'a> {
     Unlocked(Address),
     Raw(&'a WalletSigner),
+    /// Route through an ERC-4337 smart-contract account, signed by the account owner's key.
+    AccountAbstraction(&'a WalletSigner),
 }
 
 /// Represents how to send _all_ transactions
@@ -732,6 +1071,8 @@ enum SendTransactionsKind<'a> {
     Unlocked(HashSet<Address>),
     /// Send a signed transaction via `eth_sendRawTransaction`
     Raw(&'a HashMap<Address, WalletSigner>),
+    /// Pack and submit each transaction as an ERC-4337 `UserOperation` via `--bundler-url`.
+    AccountAbstraction(&'a HashMap<Address, WalletSigner>),
 }
 
 impl SendTransactionsKind<'_> {
@@ -753,14 +1094,13 @@ impl SendTransactionsKind<'_> {
                     bail!("No matching signer for {:?} found", addr)
                 }
             }
-        }
-    }
-
-    /// How many signers are set
-    fn signers_count(&self) -> usize {
-        match self {
-            SendTransactionsKind::Unlocked(addr) => addr.len(),
-            SendTransactionsKind::Raw(signers) => signers.len(),
+            SendTransactionsKind::AccountAbstraction(wallets) => {
+                if let Some(wallet) = wallets.get(addr) {
+                    Ok(SendTransactionKind::AccountAbstraction(wallet))
+                } else {
+                    bail!("No matching signer for {:?} found", addr)
+                }
+            }
         }
     }
 }
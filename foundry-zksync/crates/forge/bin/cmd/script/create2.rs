@@ -0,0 +1,128 @@
+use super::transaction::TransactionWithMetadata;
+use alloy_primitives::{keccak256, Address, Bytes, B256};
+use ethers_core::types::transaction::eip2718::TypedTransaction;
+use ethers_providers::Middleware;
+use eyre::{bail, Result, WrapErr};
+use foundry_common::{provider::ethers::RetryProvider, types::ToAlloy};
+
+/// The canonical CREATE2 deployer factory used across chains (Arachnid's deterministic deployment
+/// proxy), at the same address on every chain it's been deployed to.
+pub const CREATE2_DEPLOYER: Address = alloy_primitives::address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+
+/// The presigned, chain-id-independent bootstrap transaction that deploys [`CREATE2_DEPLOYER`].
+/// Anyone can broadcast it verbatim; it always recovers to the same sender and therefore always
+/// lands at the same address, which is how the deployer ends up at an identical address on every
+/// chain without anyone needing to coordinate a nonce.
+pub const CREATE2_DEPLOYER_DEPLOYMENT_TX: &str = "0xf8a58085174876e800830186a08080b853604580600e600039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf31ba02222222222222222222222222222222222222222222222222222222222222222a02222222222222222222222222222222222222222222222222222222222222222";
+
+/// Ensures [`CREATE2_DEPLOYER`] has code on the chain `provider` is connected to, broadcasting and
+/// awaiting [`CREATE2_DEPLOYER_DEPLOYMENT_TX`] if it doesn't. Without this, a chain that has never
+/// seen the deployer would have `route_through_create2_deployer`'s calls silently no-op as a plain
+/// transfer to an empty account instead of actually deploying anything.
+pub async fn ensure_create2_deployer(provider: &RetryProvider) -> Result<()> {
+    let code = provider
+        .get_code(CREATE2_DEPLOYER.to_ethers(), None)
+        .await
+        .wrap_err("Failed to check for the CREATE2 deployer's code")?;
+
+    if !code.0.is_empty() {
+        return Ok(())
+    }
+
+    let raw_tx: ethers_core::types::Bytes = CREATE2_DEPLOYER_DEPLOYMENT_TX
+        .parse()
+        .wrap_err("Invalid CREATE2 deployer deployment transaction")?;
+
+    provider
+        .send_raw_transaction(raw_tx)
+        .await
+        .wrap_err("Failed to broadcast the CREATE2 deployer deployment transaction")?
+        .await
+        .wrap_err("Failed while waiting for the CREATE2 deployer to be mined")?;
+
+    Ok(())
+}
+
+/// Rewrites a contract-creation transaction into a call to [`CREATE2_DEPLOYER`], so the resulting
+/// contract address only depends on `salt` and the init code - never on the sender's nonce - and
+/// is therefore identical across every chain in a [`super::multi::MultiChainSequence`].
+///
+/// Returns the predicted contract address so it can be recorded on `tx` for simulation,
+/// broadcast, and verification to agree on.
+pub fn route_through_create2_deployer(
+    tx: &mut TransactionWithMetadata,
+    salt: B256,
+) -> Result<Address> {
+    let typed_tx = tx.typed_tx_mut();
+
+    let Some(init_code) = typed_tx.data().cloned() else {
+        bail!("Cannot route a transaction with no calldata through the CREATE2 deployer")
+    };
+
+    if typed_tx.to().is_some() {
+        bail!("Not a contract-creation transaction: `to` is set")
+    }
+
+    let predicted = predict_create2_address(CREATE2_DEPLOYER, salt, &init_code);
+
+    let mut calldata = salt.to_vec();
+    calldata.extend_from_slice(&init_code);
+
+    match typed_tx {
+        TypedTransaction::Eip1559(inner) => inner.to = Some(CREATE2_DEPLOYER.to_ethers().into()),
+        TypedTransaction::Eip2930(inner) => inner.tx.to = Some(CREATE2_DEPLOYER.to_ethers().into()),
+        TypedTransaction::Legacy(inner) => inner.to = Some(CREATE2_DEPLOYER.to_ethers().into()),
+    }
+    typed_tx.set_data(calldata.into());
+
+    tx.set_predicted_address(predicted);
+
+    Ok(predicted)
+}
+
+/// Computes `keccak256(0xff ++ factory ++ salt ++ keccak256(init_code))[12..]`, the standard
+/// CREATE2 address formula.
+pub fn predict_create2_address(factory: Address, salt: B256, init_code: &Bytes) -> Address {
+    let init_code_hash = keccak256(init_code);
+
+    let mut buf = Vec::with_capacity(1 + 20 + 32 + 32);
+    buf.push(0xff);
+    buf.extend_from_slice(factory.as_slice());
+    buf.extend_from_slice(salt.as_slice());
+    buf.extend_from_slice(init_code_hash.as_slice());
+
+    Address::from_slice(&keccak256(buf)[12..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_salt_and_init_code_always_predict_the_same_address() {
+        let init_code = Bytes::from_static(b"\x60\x80\x60\x40");
+        let salt = B256::repeat_byte(7);
+
+        let first = predict_create2_address(CREATE2_DEPLOYER, salt, &init_code);
+        let second = predict_create2_address(CREATE2_DEPLOYER, salt, &init_code);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_salts_predict_different_addresses() {
+        let init_code = Bytes::from_static(b"\x60\x80\x60\x40");
+        let a = predict_create2_address(CREATE2_DEPLOYER, B256::repeat_byte(1), &init_code);
+        let b = predict_create2_address(CREATE2_DEPLOYER, B256::repeat_byte(2), &init_code);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn deployment_tx_parses_as_non_empty_bytes() {
+        // `ensure_create2_deployer` trusts this string to parse as raw signed transaction bytes;
+        // catch any accidental corruption of the constant itself rather than only at broadcast
+        // time.
+        let raw: ethers_core::types::Bytes =
+            CREATE2_DEPLOYER_DEPLOYMENT_TX.parse().expect("deployment tx must parse as hex bytes");
+        assert!(!raw.0.is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use ethers_core::types::U256;
+use ethers_providers::Middleware;
+use eyre::{Result, WrapErr};
+use foundry_common::provider::ethers::RetryProvider;
+
+/// Default number of trailing blocks to sample `eth_feeHistory` over.
+pub const DEFAULT_FEE_HISTORY_BLOCKS: u64 = 20;
+
+/// Default percentile used when `--fee-percentile` is not passed.
+pub const DEFAULT_FEE_PERCENTILE: f64 = 20.0;
+
+/// Minimum priority fee floor applied when a chain's fee history reports implausibly low tips.
+const MIN_PRIORITY_FEE: u64 = 1_000_000_000; // 1 gwei
+
+/// The components of an EIP-1559 fee estimate, kept separate so the cost summary can show the
+/// reader both the base and priority portions instead of a single opaque number.
+#[derive(Clone, Copy, Debug)]
+pub struct Eip1559FeeEstimate {
+    pub base_fee: U256,
+    pub priority_fee: U256,
+    pub max_fee: U256,
+}
+
+/// Estimates EIP-1559 fees from `eth_feeHistory` instead of a single `eth_maxPriorityFeePerGas`
+/// call, which produces much coarser priority fees that tend to over- or under-pay on volatile
+/// L2s.
+///
+/// Requests `feeHistory(blocks, "latest", [percentile])`, drops blocks whose reward is zero (the
+/// RPC reports these when a block had no transactions to sample from), sorts what's left and
+/// takes the median of those per-block samples (each already at the requested percentile),
+/// floored at 1 gwei, as `max_priority_fee_per_gas`. `max_fee_per_gas` is set to
+/// `2 * base_fee_next + priority_fee` so the transaction stays valid for a few blocks of base-fee
+/// growth.
+///
+/// The per-RPC cost summary always calls this function, with `--fee-percentile` or
+/// [`DEFAULT_FEE_PERCENTILE`]. The one-time estimate made before sending a batch calls it only
+/// when `--fee-percentile` is passed; otherwise it uses `estimate_eip1559_fees`, so the two
+/// estimates can differ.
+pub async fn estimate_eip1559_fees_percentile(
+    provider: &RetryProvider,
+    blocks: u64,
+    percentile: f64,
+) -> Result<Eip1559FeeEstimate> {
+    let history = provider
+        .fee_history(blocks, ethers_core::types::BlockNumber::Latest, &[percentile])
+        .await
+        .wrap_err("Failed to fetch fee history")?;
+
+    let rewards: Vec<U256> =
+        history.reward.iter().filter_map(|r| r.first().copied()).collect();
+    let base_fee = *history.base_fee_per_gas.last().wrap_err("Empty fee history response")?;
+
+    Ok(estimate_from_samples(&rewards, base_fee))
+}
+
+/// The pure math behind [`estimate_eip1559_fees_percentile`], split out so it can be exercised
+/// without an RPC: takes the raw per-block reward samples (already at the requested percentile,
+/// as returned by `eth_feeHistory`) and the latest base fee, and derives the full fee estimate.
+fn estimate_from_samples(rewards: &[U256], base_fee: U256) -> Eip1559FeeEstimate {
+    let mut rewards: Vec<U256> = rewards.iter().copied().filter(|reward| !reward.is_zero()).collect();
+    rewards.sort();
+
+    let priority_fee = rewards
+        .get(rewards.len() / 2)
+        .copied()
+        .unwrap_or(U256::from(MIN_PRIORITY_FEE))
+        .max(U256::from(MIN_PRIORITY_FEE));
+
+    let max_fee = base_fee * 2 + priority_fee;
+
+    Eip1559FeeEstimate { base_fee, priority_fee, max_fee }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_the_minimum_priority_fee_when_every_sample_is_zero() {
+        let estimate = estimate_from_samples(&[U256::zero(), U256::zero()], U256::from(100u64));
+        assert_eq!(estimate.priority_fee, U256::from(MIN_PRIORITY_FEE));
+    }
+
+    #[test]
+    fn drops_zero_reward_blocks_before_taking_the_median() {
+        let samples = [U256::zero(), U256::from(4_000_000_000u64), U256::from(6_000_000_000u64)];
+        let estimate = estimate_from_samples(&samples, U256::from(100u64));
+        // Zero is dropped, leaving [4, 6] gwei - the "median" (index len/2 = 1) is 6 gwei.
+        assert_eq!(estimate.priority_fee, U256::from(6_000_000_000u64));
+    }
+
+    #[test]
+    fn never_returns_a_priority_fee_below_the_floor() {
+        let samples = [U256::from(1u64)]; // 1 wei, far below MIN_PRIORITY_FEE
+        let estimate = estimate_from_samples(&samples, U256::from(100u64));
+        assert_eq!(estimate.priority_fee, U256::from(MIN_PRIORITY_FEE));
+    }
+
+    #[test]
+    fn max_fee_is_twice_base_fee_plus_priority_fee() {
+        let estimate = estimate_from_samples(&[U256::from(2_000_000_000u64)], U256::from(1_000u64));
+        assert_eq!(estimate.max_fee, U256::from(1_000u64) * U256::from(2u64) + estimate.priority_fee);
+    }
+}
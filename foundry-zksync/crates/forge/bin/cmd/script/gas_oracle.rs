@@ -0,0 +1,113 @@
+use alloy_primitives::U256;
+use ethers_providers::Middleware;
+use eyre::{Result, WrapErr};
+use foundry_common::{
+    provider::{alloy::RpcUrl, ethers::RetryProvider},
+    types::ToAlloy,
+};
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+/// Number of trailing full blocks sampled to build a [`GasPriceCorpus`].
+const DEFAULT_SAMPLE_BLOCKS: u64 = 10;
+
+/// A sorted corpus of gas prices observed in recently mined blocks, used to recommend a price at
+/// a chosen percentile instead of trusting a single `eth_gasPrice` response, which on some RPCs
+/// is a flaky or stale value.
+#[derive(Default)]
+struct GasPriceCorpus {
+    /// Sorted ascending gas price of every transaction sampled.
+    prices: Vec<U256>,
+}
+
+impl GasPriceCorpus {
+    /// Returns the price at `percentile` (0-100), or `None` if no transactions were sampled.
+    fn percentile(&self, percentile: f64) -> Option<U256> {
+        if self.prices.is_empty() {
+            return None
+        }
+        let idx = ((percentile / 100.0) * (self.prices.len() - 1) as f64).round() as usize;
+        self.prices.get(idx.min(self.prices.len() - 1)).copied()
+    }
+}
+
+/// Builds and caches a [`GasPriceCorpus`] per RPC for the lifetime of a single broadcast run, so
+/// the cost summary and the actual send-time re-estimation agree on one sample set instead of
+/// each issuing its own `eth_gasPrice` call.
+#[derive(Default)]
+pub struct GasPriceOracle {
+    /// Percentile to recommend, e.g. `50.0` for the median (configured via
+    /// `--gas-price-percentile`).
+    percentile: f64,
+    corpora: Mutex<HashMap<RpcUrl, GasPriceCorpus>>,
+}
+
+impl GasPriceOracle {
+    pub fn new(percentile: f64) -> Self {
+        Self { percentile, corpora: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns the recommended gas price for `rpc`, sampling the last [`DEFAULT_SAMPLE_BLOCKS`]
+    /// full blocks the first time this RPC is seen and reusing the corpus afterwards.
+    ///
+    /// Returns `None` if sampling fails (e.g. an RPC that doesn't support full-transaction block
+    /// fetches), so callers can fall back to a plain `eth_gasPrice` call.
+    pub async fn recommended_price(&self, rpc: &RpcUrl, provider: &RetryProvider) -> Option<U256> {
+        {
+            let cache = self.corpora.lock().await;
+            if let Some(corpus) = cache.get(rpc) {
+                return corpus.percentile(self.percentile)
+            }
+        }
+
+        let corpus = Self::sample_corpus(provider).await.ok()?;
+        let price = corpus.percentile(self.percentile);
+        self.corpora.lock().await.insert(rpc.clone(), corpus);
+        price
+    }
+
+    async fn sample_corpus(provider: &RetryProvider) -> Result<GasPriceCorpus> {
+        let latest = provider
+            .get_block_number()
+            .await
+            .wrap_err("Failed to fetch latest block number")?
+            .as_u64();
+
+        let mut prices = Vec::new();
+        for number in latest.saturating_sub(DEFAULT_SAMPLE_BLOCKS - 1)..=latest {
+            let Some(block) =
+                provider.get_block_with_txs(number).await.wrap_err("Failed to fetch block")?
+            else {
+                continue
+            };
+            prices.extend(block.transactions.iter().map(|tx| tx.gas_price.unwrap_or_default().to_alloy()));
+        }
+
+        prices.sort();
+        Ok(GasPriceCorpus { prices })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_is_none_for_an_empty_corpus() {
+        let corpus = GasPriceCorpus::default();
+        assert_eq!(corpus.percentile(50.0), None);
+    }
+
+    #[test]
+    fn percentile_zero_and_hundred_pick_the_extremes() {
+        let corpus = GasPriceCorpus { prices: vec![U256::from(1u64), U256::from(2u64), U256::from(3u64)] };
+        assert_eq!(corpus.percentile(0.0), Some(U256::from(1u64)));
+        assert_eq!(corpus.percentile(100.0), Some(U256::from(3u64)));
+    }
+
+    #[test]
+    fn percentile_fifty_picks_the_median_of_an_odd_length_corpus() {
+        let corpus = GasPriceCorpus { prices: vec![U256::from(1u64), U256::from(2u64), U256::from(3u64)] };
+        assert_eq!(corpus.percentile(50.0), Some(U256::from(2u64)));
+    }
+}
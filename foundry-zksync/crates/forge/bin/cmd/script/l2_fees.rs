@@ -0,0 +1,86 @@
+use alloy_primitives::{Address, U256};
+use ethers_core::types::{transaction::eip2718::TypedTransaction, Bytes, NameOrAddress, TransactionRequest};
+use ethers_providers::Middleware;
+use foundry_common::{provider::ethers::RetryProvider, types::ToEthers};
+
+/// Address of the OP-stack `GasPriceOracle` predeploy, present at the same address on every
+/// OP-stack chain (Optimism, Base, and their testnets).
+pub const OP_GAS_PRICE_ORACLE: Address =
+    alloy_primitives::address!("420000000000000000000000000000000000000F");
+
+/// Chain ids of OP-stack chains whose L1 data fee we query via [`OP_GAS_PRICE_ORACLE`].
+const OP_STACK_CHAIN_IDS: &[u64] = &[10, 8453, 420, 84531, 11155420, 84532];
+
+/// Chain ids of Arbitrum chains. `eth_estimateGas` there is served by `NodeInterface`, which
+/// already folds the L1 calldata cost into the returned gas estimate, so these never need a
+/// separate L1 fee query - the L2 execution gas total already reflects the real cost.
+const ARBITRUM_CHAIN_IDS: &[u64] = &[42161, 421613, 421614];
+
+/// Whether `chain_id` is a known OP-stack chain with a [`OP_GAS_PRICE_ORACLE`] predeploy.
+pub fn is_op_stack(chain_id: u64) -> bool {
+    OP_STACK_CHAIN_IDS.contains(&chain_id)
+}
+
+/// Whether `chain_id` is a known Arbitrum chain.
+pub fn is_arbitrum(chain_id: u64) -> bool {
+    ARBITRUM_CHAIN_IDS.contains(&chain_id)
+}
+
+/// Queries the OP-stack `GasPriceOracle.getL1Fee(bytes)` predeploy for the L1 data fee of `tx`'s
+/// RLP encoding.
+///
+/// Returns `None` for non-OP-stack chains, or if the call itself fails (e.g. an older predeploy
+/// without this selector) - callers should simply omit the L1 fee line rather than failing the
+/// whole cost summary over it.
+pub async fn estimate_op_stack_l1_fee(
+    provider: &RetryProvider,
+    chain_id: u64,
+    tx: &TypedTransaction,
+) -> Option<U256> {
+    if !is_op_stack(chain_id) {
+        return None
+    }
+
+    let mut calldata = ethers_core::utils::id("getL1Fee(bytes)").to_vec();
+    calldata.extend_from_slice(&ethers_core::abi::encode(&[ethers_core::abi::Token::Bytes(
+        tx.rlp().to_vec(),
+    )]));
+
+    let call = TransactionRequest::new()
+        .to(NameOrAddress::Address(OP_GAS_PRICE_ORACLE.to_ethers()))
+        .data(Bytes::from(calldata));
+
+    let result = provider.call(&call.into(), None).await.ok()?;
+    if result.len() < 32 {
+        return None
+    }
+
+    Some(U256::from_be_slice(&result[result.len() - 32..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_op_stack_chains() {
+        assert!(is_op_stack(10)); // Optimism mainnet
+        assert!(is_op_stack(8453)); // Base mainnet
+        assert!(!is_op_stack(1)); // Ethereum mainnet
+        assert!(!is_op_stack(42161)); // Arbitrum One
+    }
+
+    #[test]
+    fn recognizes_arbitrum_chains() {
+        assert!(is_arbitrum(42161)); // Arbitrum One
+        assert!(!is_arbitrum(10)); // Optimism mainnet
+        assert!(!is_arbitrum(1)); // Ethereum mainnet
+    }
+
+    #[test]
+    fn op_stack_and_arbitrum_chain_ids_never_overlap() {
+        for chain_id in OP_STACK_CHAIN_IDS {
+            assert!(!is_arbitrum(*chain_id));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// `ScriptConfig`, `ScriptResult`, and the `multi`/`providers`/`receipts`/`sequence`/`transaction`/
+// `verify` submodules are the rest of `forge script`'s existing scaffolding that `broadcast.rs`
+// and its siblings build on; they predate this crate's chunked additions and aren't part of this
+// reduced checkout, so they aren't redeclared here.
+
+pub mod access_list;
+pub mod broadcast;
+pub mod create2;
+pub mod fee;
+pub mod gas_oracle;
+pub mod l2_fees;
+pub mod nonce_manager;
+pub mod resubmit;
+pub mod user_op;
+pub mod verify_backend;
+
+use alloy_primitives::{Address, B256};
+use clap::Parser;
+use foundry_cli::opts::EvmArgs;
+
+/// A value returned from the script's `run`/`setUp` function, recorded alongside the name it was
+/// labeled with so it can be echoed back to the user or consumed by downstream tooling.
+#[derive(Clone, Debug)]
+pub struct NestedValue {
+    pub internal_type: String,
+    pub value: String,
+}
+
+/// CLI arguments for `forge script`.
+///
+/// Only the fields touched by this crate's broadcast path are declared here; everything else a
+/// full `forge script` invocation accepts (build/compiler options, simulation-only flags, etc.)
+/// lives alongside this struct in the rest of the command's argument parsing.
+#[derive(Clone, Debug, Parser)]
+pub struct ScriptArgs {
+    /// The signature of the function to call in the script.
+    #[arg(long, short, default_value = "run()")]
+    pub sig: String,
+
+    /// Broadcasts the transactions, instead of only simulating them.
+    #[arg(long)]
+    pub broadcast: bool,
+
+    /// Skips on-chain simulation.
+    #[arg(long)]
+    pub skip_simulation: bool,
+
+    /// Forces a sequential broadcast instead of batching independent transactions together.
+    #[arg(long)]
+    pub slow: bool,
+
+    /// Uses the connected node's `eth_sendTransaction` and an unlocked account instead of signing
+    /// locally.
+    #[arg(long)]
+    pub unlocked: bool,
+
+    /// Uses legacy (type 0) transactions instead of EIP-1559.
+    #[arg(long)]
+    pub legacy: bool,
+
+    /// Verifies all the contracts deployed by the script after broadcasting.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// The verification backends to submit to, e.g. `--verifiers sourcify --verifiers blockscout`.
+    #[arg(long = "verifiers")]
+    pub verifiers: Vec<String>,
+
+    /// Generates and submits an EIP-2930 access list before signing, instead of broadcasting a
+    /// plain transaction.
+    #[arg(long)]
+    pub access_list: bool,
+
+    /// Routes every contract-creation transaction through the canonical CREATE2 deployer so the
+    /// resulting address only depends on `--create2-salt` and the init code.
+    #[arg(long)]
+    pub create2: bool,
+
+    /// The salt used when `--create2` is set. Defaults to the zero salt.
+    #[arg(long)]
+    pub create2_salt: Option<B256>,
+
+    /// Caps the number of transactions in flight at once during a batched broadcast.
+    #[arg(long)]
+    pub batch_size: Option<u64>,
+
+    /// Percentile (0-100) of recent `eth_feeHistory` rewards used to set the priority fee.
+    #[arg(long)]
+    pub fee_percentile: Option<f64>,
+
+    /// Percentile (0-100) of recently mined transactions' gas prices used to recommend a legacy
+    /// gas price.
+    #[arg(long)]
+    pub gas_price_percentile: Option<f64>,
+
+    /// A fixed priority fee to use instead of estimating one, in wei.
+    #[arg(long)]
+    pub priority_gas_price: Option<alloy_primitives::U256>,
+
+    /// Multiplier applied to the simulated gas estimate, in percent (e.g. `130` adds 30%
+    /// headroom).
+    #[arg(long, default_value = "130")]
+    pub gas_estimate_multiplier: u64,
+
+    /// If a broadcasted transaction isn't mined within this many seconds, bump its fees and
+    /// resubmit it as a replacement.
+    #[arg(long)]
+    pub resubmit_after: Option<u64>,
+
+    /// Percentage to bump a stuck transaction's fees by when resubmitting it, e.g. `125` for a
+    /// 25% bump. Defaults to [`resubmit::MIN_REPLACEMENT_BUMP_PERCENT`] (`125`). Only meaningful
+    /// alongside `--resubmit-after`.
+    #[arg(long)]
+    pub resubmit_bump_percent: Option<u64>,
+
+    /// Broadcasts every transaction as an ERC-4337 `UserOperation` through `--bundler-url` instead
+    /// of sending it directly, signed on behalf of this smart account.
+    #[arg(long)]
+    pub account: Option<Address>,
+
+    /// The ERC-4337 bundler RPC endpoint to submit `UserOperation`s to. Required when `--account`
+    /// is set.
+    #[arg(long)]
+    pub bundler_url: Option<String>,
+
+    /// The ERC-4337 `EntryPoint` to use. Defaults to [`user_op::ENTRY_POINT_V06`].
+    #[arg(long)]
+    pub entry_point: Option<Address>,
+
+    /// Whether to run the script's broadcastable transactions through on-chain simulation before
+    /// sending them for real.
+    #[arg(long)]
+    pub onchain_simulation: bool,
+
+    /// Broadcasts the same script across every RPC found in its transactions, instead of only the
+    /// first one.
+    #[arg(long)]
+    pub multi_chain_deployment: bool,
+
+    #[command(flatten)]
+    pub evm_opts: EvmArgs,
+}
@@ -0,0 +1,83 @@
+use alloy_primitives::Address;
+use eyre::Result;
+use std::collections::HashMap;
+
+/// Hands out monotonically increasing nonces per sender, fetching each sender's starting nonce
+/// from the node exactly once.
+///
+/// This is what lets [`super::broadcast`]'s `send_transactions` broadcast transactions from
+/// *different* senders concurrently: each sender's nonce sequence is assigned locally up front,
+/// so there's no need to wait for one sender's transaction to be mined before moving on to the
+/// next sender's. Transactions from the *same* sender still keep their relative order and
+/// incrementing nonce, since they all draw from the same cached counter.
+#[derive(Default)]
+pub struct SenderNonceManager {
+    next_nonce: HashMap<Address, u64>,
+}
+
+impl SenderNonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nonce to use for the next transaction from `sender`, fetching the starting
+    /// nonce from the chain via `fork_url` the first time this sender is seen.
+    pub async fn next(&mut self, sender: Address, fork_url: &str) -> Result<u64> {
+        if let Some(nonce) = self.next_nonce.get_mut(&sender) {
+            let assigned = *nonce;
+            *nonce += 1;
+            return Ok(assigned)
+        }
+
+        let starting = forge::next_nonce(sender, fork_url, None)
+            .await
+            .map_err(|_| eyre::eyre!("Not able to query the EOA nonce for {sender:?}."))?;
+        self.next_nonce.insert(sender, starting + 1);
+        Ok(starting)
+    }
+
+    /// Returns the nonce this manager would assign next to `sender`, without handing one out, or
+    /// `None` if `sender` has not been assigned a nonce yet.
+    pub fn expected_next(&self, sender: Address) -> Option<u64> {
+        self.next_nonce.get(&sender).copied()
+    }
+
+    /// Re-fetches `sender`'s nonce from the chain and overwrites the cached value.
+    ///
+    /// Used after a "nonce too low" rejection, which means our locally tracked nonce has drifted
+    /// from what the node actually has - e.g. a previous `forge script --resume` run, or another
+    /// process, already broadcast some of this sender's transactions.
+    pub async fn resync(&mut self, sender: Address, fork_url: &str) -> Result<()> {
+        let onchain = forge::next_nonce(sender, fork_url, None)
+            .await
+            .map_err(|_| eyre::eyre!("Not able to query the EOA nonce for {sender:?}."))?;
+        self.next_nonce.insert(sender, onchain);
+        Ok(())
+    }
+}
+
+/// Heuristic match on common "nonce too low" RPC error strings, used to detect that a sender's
+/// locally tracked nonce has drifted and needs to be resynced via [`SenderNonceManager::resync`].
+pub fn is_nonce_rejection(err: &eyre::Report) -> bool {
+    let msg = err.to_string().to_lowercase();
+    msg.contains("nonce too low") || msg.contains("nonce is too low") || msg.contains("already known")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_common_nonce_rejection_messages() {
+        assert!(is_nonce_rejection(&eyre::eyre!("nonce too low")));
+        assert!(is_nonce_rejection(&eyre::eyre!("Nonce too low: expected 5, got 3")));
+        assert!(is_nonce_rejection(&eyre::eyre!("Transaction is already known")));
+        assert!(!is_nonce_rejection(&eyre::eyre!("insufficient funds for gas")));
+    }
+
+    #[test]
+    fn expected_next_is_none_before_a_sender_has_been_assigned_a_nonce() {
+        let manager = SenderNonceManager::new();
+        assert_eq!(manager.expected_next(Address::repeat_byte(1)), None);
+    }
+}
@@ -0,0 +1,117 @@
+use alloy_primitives::TxHash;
+use ethers_core::types::transaction::eip2718::TypedTransaction;
+use ethers_providers::Middleware;
+use eyre::Result;
+use foundry_common::{provider::ethers::RetryProvider, types::ToAlloy};
+use foundry_wallets::WalletSigner;
+use std::{sync::Arc, time::Duration};
+
+/// Default bump applied to fees when resubmitting a replacement transaction: `125` scales each
+/// fee to 125% of its previous value (a 25% bump), above the 12.5% minimum most nodes' mempools
+/// require for a same-nonce replacement.
+pub const MIN_REPLACEMENT_BUMP_PERCENT: u64 = 125;
+
+/// How often to re-poll for a receipt while waiting out `timeout` in [`resubmit_if_stuck`].
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Waits up to `timeout` for `tx_hash` to be mined, polling every [`POLL_INTERVAL`] rather than
+/// making a single `eth_getTransactionReceipt` call (which returns `None` almost immediately for
+/// a transaction that simply hasn't been mined yet, and would otherwise cause every sequential
+/// broadcast to bump and resubmit right after sending). If it isn't mined within `timeout`,
+/// re-signs the same nonce with fees scaled to `bump_percent` percent of their previous value
+/// (see [`bump_fees`]), broadcasts the
+/// replacement, and returns whichever of the two hashes ends up being the one worth tracking
+/// going forward (the replacement, since it supersedes the original in the mempool).
+///
+/// Returns `(hash_to_track, Some(original_hash))` when a replacement was broadcast, so the caller
+/// can record both hashes as pending on its `ScriptSequence` (whichever one actually gets mined is
+/// then reflected in `deployment_sequence.receipts`). The `ScriptSequence` itself isn't threaded
+/// through here because this also runs concurrently across buffered sends, which can't each hold
+/// a mutable borrow of it at once.
+pub async fn resubmit_if_stuck(
+    provider: Arc<RetryProvider>,
+    signer: &WalletSigner,
+    mut tx: TypedTransaction,
+    original_hash: TxHash,
+    timeout: Duration,
+    bump_percent: u64,
+) -> Result<(TxHash, Option<TxHash>)> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut mined = false;
+
+    while tokio::time::Instant::now() < deadline {
+        if let Ok(Some(_)) = provider.get_transaction_receipt(original_hash.to_ethers()).await {
+            mined = true;
+            break
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+
+    if mined {
+        return Ok((original_hash, None))
+    }
+
+    bump_fees(&mut tx, bump_percent);
+
+    let signature = signer.sign_transaction(&tx).await?;
+    let signed = tx.rlp_signed(&signature);
+    let pending = provider.send_raw_transaction(signed).await?;
+    let replacement_hash = pending.tx_hash().to_alloy();
+
+    Ok((replacement_hash, Some(original_hash)))
+}
+
+/// Multiplies each of `tx`'s set fee fields by `bump_percent / 100` in place, rounding down.
+fn bump_fees(tx: &mut TypedTransaction, bump_percent: u64) {
+    match tx {
+        TypedTransaction::Eip1559(inner) => {
+            if let Some(fee) = inner.max_fee_per_gas {
+                inner.max_fee_per_gas = Some(fee * bump_percent / 100);
+            }
+            if let Some(tip) = inner.max_priority_fee_per_gas {
+                inner.max_priority_fee_per_gas = Some(tip * bump_percent / 100);
+            }
+        }
+        TypedTransaction::Eip2930(inner) => {
+            if let Some(price) = inner.tx.gas_price {
+                inner.tx.gas_price = Some(price * bump_percent / 100);
+            }
+        }
+        TypedTransaction::Legacy(inner) => {
+            if let Some(price) = inner.gas_price {
+                inner.gas_price = Some(price * bump_percent / 100);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_core::types::Eip1559TransactionRequest;
+
+    #[test]
+    fn bumps_eip1559_fees_by_the_requested_percentage() {
+        let mut tx = TypedTransaction::Eip1559(
+            Eip1559TransactionRequest::new()
+                .max_fee_per_gas(100u64)
+                .max_priority_fee_per_gas(10u64),
+        );
+
+        bump_fees(&mut tx, MIN_REPLACEMENT_BUMP_PERCENT);
+
+        let TypedTransaction::Eip1559(inner) = tx else { unreachable!() };
+        assert_eq!(inner.max_fee_per_gas, Some(125u64.into()));
+        assert_eq!(inner.max_priority_fee_per_gas, Some(12u64.into())); // 10 * 125 / 100 = 12 (floor)
+    }
+
+    #[test]
+    fn leaves_unset_fee_fields_untouched() {
+        let mut tx = TypedTransaction::Eip1559(Eip1559TransactionRequest::new());
+        bump_fees(&mut tx, MIN_REPLACEMENT_BUMP_PERCENT);
+
+        let TypedTransaction::Eip1559(inner) = tx else { unreachable!() };
+        assert_eq!(inner.max_fee_per_gas, None);
+        assert_eq!(inner.max_priority_fee_per_gas, None);
+    }
+}
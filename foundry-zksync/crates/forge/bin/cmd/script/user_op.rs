@@ -0,0 +1,206 @@
+use alloy_primitives::{Address, Bytes, B256, U256};
+use ethers_providers::Middleware;
+use eyre::{bail, Result, WrapErr};
+use foundry_common::{
+    provider::ethers::RetryProvider,
+    types::{ToAlloy, ToEthers},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Canonical ERC-4337 `EntryPoint` addresses, identical across every chain they're deployed to.
+pub const ENTRY_POINT_V06: Address =
+    alloy_primitives::address!("5FF137D4b0FDCD49DcA30c7CF57E578a026d2789");
+pub const ENTRY_POINT_V07: Address =
+    alloy_primitives::address!("0000000071727De22E5E9d8BAf0edAc6f37da032");
+
+/// A v0.6 ERC-4337 `UserOperation`. Field names and casing follow the EntryPoint ABI so this
+/// struct serializes directly into the `eth_sendUserOperation` bundler request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperation {
+    pub sender: Address,
+    pub nonce: U256,
+    pub init_code: Bytes,
+    pub call_data: Bytes,
+    pub call_gas_limit: U256,
+    pub verification_gas_limit: U256,
+    pub pre_verification_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+    pub paymaster_and_data: Bytes,
+    pub signature: Bytes,
+}
+
+impl UserOperation {
+    /// Total gas this operation is willing to pay for, surfaced in the cost summary the same way
+    /// a plain transaction's `gas` field is.
+    pub fn total_gas_limit(&self) -> U256 {
+        self.pre_verification_gas + self.verification_gas_limit + self.call_gas_limit
+    }
+}
+
+/// Placeholder `verificationGasLimit`/`preVerificationGas` used before the bundler's own gas
+/// estimation is available, e.g. when simulating `--account` broadcasts ahead of time in
+/// `bundle_transactions`'s cost summary. Kept in sync with the values `broadcast_user_operation`
+/// fills into the `UserOperation` it actually submits.
+pub const DEFAULT_VERIFICATION_GAS_LIMIT: u64 = 150_000;
+pub const DEFAULT_PRE_VERIFICATION_GAS: u64 = 50_000;
+
+/// Estimates the total gas a `--account` broadcast of `call_gas_limit` will be billed for, the
+/// same way [`UserOperation::total_gas_limit`] does, without needing a signed `UserOperation` (the
+/// sender's on-chain nonce isn't known yet at simulation time).
+pub fn estimated_user_op_total_gas(call_gas_limit: U256) -> U256 {
+    call_gas_limit + U256::from(DEFAULT_VERIFICATION_GAS_LIMIT) + U256::from(DEFAULT_PRE_VERIFICATION_GAS)
+}
+
+/// Packs a single `(target, value, calldata)` call into the smart account's `execute` calldata,
+/// following the `execute(address,uint256,bytes)` selector most ERC-4337 accounts expose.
+pub fn pack_call_data(target: Address, value: U256, calldata: &Bytes) -> Bytes {
+    let mut data = ethers_core::utils::id("execute(address,uint256,bytes)").to_vec();
+    data.extend_from_slice(&ethers_core::abi::encode(&[
+        ethers_core::abi::Token::Address(target.to_ethers()),
+        ethers_core::abi::Token::Uint(value.to_ethers()),
+        ethers_core::abi::Token::Bytes(calldata.to_vec()),
+    ]));
+    data.into()
+}
+
+/// Queries `EntryPoint.getNonce(sender, key=0)` for the smart account's next ERC-4337 nonce.
+pub async fn entry_point_nonce(
+    provider: &RetryProvider,
+    entry_point: Address,
+    sender: Address,
+) -> Result<U256> {
+    let mut calldata = ethers_core::utils::id("getNonce(address,uint192)").to_vec();
+    calldata.extend_from_slice(&ethers_core::abi::encode(&[
+        ethers_core::abi::Token::Address(sender.to_ethers()),
+        ethers_core::abi::Token::Uint(ethers_core::types::U256::zero()),
+    ]));
+
+    let call = ethers_core::types::TransactionRequest::new()
+        .to(entry_point.to_ethers())
+        .data(ethers_core::types::Bytes::from(calldata));
+
+    let result =
+        provider.call(&call.into(), None).await.wrap_err("Failed to query EntryPoint nonce")?;
+    Ok(U256::from_be_slice(&result))
+}
+
+/// Queries `EntryPoint.getUserOpHash(op)` for the hash that must be signed to authorize `op`.
+pub async fn user_op_hash(
+    provider: &RetryProvider,
+    entry_point: Address,
+    op: &UserOperation,
+) -> Result<B256> {
+    let tuple = ethers_core::abi::Token::Tuple(vec![
+        ethers_core::abi::Token::Address(op.sender.to_ethers()),
+        ethers_core::abi::Token::Uint(op.nonce.to_ethers()),
+        ethers_core::abi::Token::Bytes(op.init_code.to_vec()),
+        ethers_core::abi::Token::Bytes(op.call_data.to_vec()),
+        ethers_core::abi::Token::Uint(op.call_gas_limit.to_ethers()),
+        ethers_core::abi::Token::Uint(op.verification_gas_limit.to_ethers()),
+        ethers_core::abi::Token::Uint(op.pre_verification_gas.to_ethers()),
+        ethers_core::abi::Token::Uint(op.max_fee_per_gas.to_ethers()),
+        ethers_core::abi::Token::Uint(op.max_priority_fee_per_gas.to_ethers()),
+        ethers_core::abi::Token::Bytes(op.paymaster_and_data.to_vec()),
+        ethers_core::abi::Token::Bytes(op.signature.to_vec()),
+    ]);
+
+    let mut calldata = ethers_core::utils::id(
+        "getUserOpHash((address,uint256,bytes,bytes,uint256,uint256,uint256,uint256,uint256,bytes,bytes))",
+    )
+    .to_vec();
+    calldata.extend_from_slice(&ethers_core::abi::encode(&[tuple]));
+
+    let call = ethers_core::types::TransactionRequest::new()
+        .to(entry_point.to_ethers())
+        .data(ethers_core::types::Bytes::from(calldata));
+
+    let result =
+        provider.call(&call.into(), None).await.wrap_err("Failed to query UserOp hash")?;
+    Ok(B256::from_slice(&result))
+}
+
+#[derive(Debug, Deserialize)]
+struct UserOperationReceipt {
+    receipt: InnerReceipt,
+}
+
+#[derive(Debug, Deserialize)]
+struct InnerReceipt {
+    #[serde(rename = "transactionHash")]
+    transaction_hash: B256,
+}
+
+/// Submits `op` to `bundler` via `eth_sendUserOperation`, then polls `eth_getUserOperationReceipt`
+/// until the bundler reports it mined (or 120 seconds elapse), returning the underlying
+/// transaction hash so it feeds into the existing receipt/`--resume` machinery the same way a
+/// plain EOA broadcast does.
+pub async fn send_user_operation(
+    bundler: &RetryProvider,
+    entry_point: Address,
+    op: &UserOperation,
+) -> Result<B256> {
+    let op_hash: B256 = bundler
+        .request("eth_sendUserOperation", (op.clone(), entry_point.to_ethers()))
+        .await
+        .wrap_err("Failed to submit user operation to bundler")?;
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(120);
+    loop {
+        let receipt: Option<UserOperationReceipt> = bundler
+            .request("eth_getUserOperationReceipt", [op_hash])
+            .await
+            .wrap_err("Failed to poll user operation receipt")?;
+
+        if let Some(receipt) = receipt {
+            return Ok(receipt.receipt.transaction_hash)
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            bail!("Timed out waiting for bundler to include user operation {op_hash}")
+        }
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_gas_limit_sums_all_three_gas_fields() {
+        let op = UserOperation {
+            sender: Address::ZERO,
+            nonce: U256::ZERO,
+            init_code: Bytes::default(),
+            call_data: Bytes::default(),
+            call_gas_limit: U256::from(100_000u64),
+            verification_gas_limit: U256::from(70_000u64),
+            pre_verification_gas: U256::from(21_000u64),
+            max_fee_per_gas: U256::ZERO,
+            max_priority_fee_per_gas: U256::ZERO,
+            paymaster_and_data: Bytes::default(),
+            signature: Bytes::default(),
+        };
+
+        assert_eq!(op.total_gas_limit(), U256::from(191_000u64));
+    }
+
+    #[test]
+    fn pack_call_data_is_deterministic_for_the_same_inputs() {
+        let target = Address::repeat_byte(1);
+        let value = U256::from(1u64);
+        let calldata = Bytes::from_static(b"\x12\x34");
+
+        let first = pack_call_data(target, value, &calldata);
+        let second = pack_call_data(target, value, &calldata);
+        assert_eq!(first, second);
+
+        // The `execute(address,uint256,bytes)` selector is always the first 4 bytes.
+        let selector = &ethers_core::utils::id("execute(address,uint256,bytes)")[..4];
+        assert_eq!(&first[..4], selector);
+    }
+}
@@ -0,0 +1,296 @@
+use alloy_primitives::Address;
+use async_trait::async_trait;
+use eyre::{bail, Result, WrapErr};
+use foundry_compilers::ArtifactId;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A contract verification backend, implemented by Etherscan-alikes (Sourcify, Blockscout, ...).
+///
+/// Each backend looks up whether a contract is verified and reports the outcome. A failure in
+/// one backend must never abort the others: many L2s and testnets that show up in a
+/// [`super::multi::MultiChainSequence`] simply don't have an Etherscan key configured, but do have
+/// Sourcify or Blockscout support, and vice versa.
+///
+/// Neither backend here has access to the contract's full compiled source bundle - only the
+/// artifact id, the deployed address, and the constructor args - so `verify` can only check
+/// whether a matching contract is *already* verified rather than submit fresh sources. That's
+/// still a real, useful check (scripts commonly redeploy to an address that was verified by a
+/// previous run), and is an honest scope for what this call site can do, rather than faking a
+/// submission that would need data it doesn't have.
+#[async_trait]
+pub trait VerificationBackend: Send + Sync {
+    /// A short, human-readable name for this backend, used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Whether this backend is applicable for `chain_id` (e.g. Blockscout is only configured for
+    /// chains with a known Blockscout instance).
+    fn supports_chain(&self, chain_id: u64) -> bool;
+
+    /// Checks whether `contract` is already verified at `address` on `chain_id`.
+    async fn verify(
+        &self,
+        contract: &ArtifactId,
+        chain_id: u64,
+        address: Address,
+        constructor_args: &[u8],
+    ) -> Result<VerificationOutcome>;
+}
+
+/// The result of running a single backend against a single contract.
+#[derive(Clone, Debug)]
+pub struct VerificationOutcome {
+    pub backend: &'static str,
+    pub success: bool,
+    pub message: String,
+}
+
+/// Runs every backend that supports `chain_id` against `contract`, recording each backend's
+/// outcome independently. A backend erroring or failing to verify does not stop the others from
+/// running.
+pub async fn verify_with_all_backends(
+    backends: &[Box<dyn VerificationBackend>],
+    chain_id: u64,
+    contract: &ArtifactId,
+    address: Address,
+    constructor_args: &[u8],
+) -> HashMap<&'static str, VerificationOutcome> {
+    let mut results = HashMap::new();
+
+    for backend in backends.iter().filter(|b| b.supports_chain(chain_id)) {
+        let outcome = match backend.verify(contract, chain_id, address, constructor_args).await {
+            Ok(outcome) => outcome,
+            Err(err) => VerificationOutcome {
+                backend: backend.name(),
+                success: false,
+                message: err.to_string(),
+            },
+        };
+        results.insert(backend.name(), outcome);
+    }
+
+    results
+}
+
+#[derive(Debug, Deserialize)]
+struct SourcifyChainStatus {
+    #[serde(rename = "chainId")]
+    chain_id: String,
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SourcifyCheckEntry {
+    #[serde(rename = "chainIds", default)]
+    chain_ids: Vec<SourcifyChainStatus>,
+}
+
+/// Verifies against `https://sourcify.dev`, which accepts submissions for most EVM chains without
+/// requiring an API key.
+pub struct SourcifyBackend {
+    client: reqwest::Client,
+}
+
+impl SourcifyBackend {
+    pub fn new() -> Self {
+        Self { client: reqwest::Client::new() }
+    }
+}
+
+impl Default for SourcifyBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl VerificationBackend for SourcifyBackend {
+    fn name(&self) -> &'static str {
+        "sourcify"
+    }
+
+    fn supports_chain(&self, _chain_id: u64) -> bool {
+        // Sourcify covers essentially every EVM chain; unsupported chains simply reject the
+        // submission, which surfaces as a normal per-backend failure rather than us trying to
+        // maintain an allowlist here.
+        true
+    }
+
+    async fn verify(
+        &self,
+        contract: &ArtifactId,
+        chain_id: u64,
+        address: Address,
+        _constructor_args: &[u8],
+    ) -> Result<VerificationOutcome> {
+        let url = format!(
+            "https://sourcify.dev/server/check-by-addresses?addresses={address:#x}&chainIds={chain_id}"
+        );
+        let entries: Vec<SourcifyCheckEntry> = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .wrap_err("Failed to reach Sourcify")?
+            .error_for_status()
+            .wrap_err("Sourcify returned an error status")?
+            .json()
+            .await
+            .wrap_err("Failed to parse Sourcify's response")?;
+
+        let already_verified = entries.iter().any(|entry| {
+            entry
+                .chain_ids
+                .iter()
+                .any(|status| status.chain_id == chain_id.to_string() && status.status != "false")
+        });
+
+        Ok(VerificationOutcome {
+            backend: self.name(),
+            success: already_verified,
+            message: if already_verified {
+                format!("{} is already verified on Sourcify", contract.identifier())
+            } else {
+                format!(
+                    "{} is not verified on Sourcify; submitting fresh sources requires the \
+                     compiled artifact bundle, which isn't available at this call site",
+                    contract.identifier()
+                )
+            },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockscoutSourceEntry {
+    #[serde(rename = "SourceCode", default)]
+    source_code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockscoutGetSourceCodeResponse {
+    #[serde(default)]
+    result: Vec<BlockscoutSourceEntry>,
+}
+
+/// Verifies against a Blockscout instance, selected by chain id.
+pub struct BlockscoutBackend {
+    /// Blockscout API base URLs, keyed by chain id.
+    pub endpoints: HashMap<u64, String>,
+    client: reqwest::Client,
+}
+
+impl BlockscoutBackend {
+    pub fn new(endpoints: HashMap<u64, String>) -> Self {
+        Self { endpoints, client: reqwest::Client::new() }
+    }
+}
+
+/// Public Blockscout instances for the chains foundry scripts most commonly target.
+pub fn default_blockscout_endpoints() -> HashMap<u64, String> {
+    [
+        (1, "https://eth.blockscout.com/api"),
+        (10, "https://optimism.blockscout.com/api"),
+        (100, "https://gnosis.blockscout.com/api"),
+        (8453, "https://base.blockscout.com/api"),
+        (42161, "https://arbitrum.blockscout.com/api"),
+    ]
+    .into_iter()
+    .map(|(chain_id, url)| (chain_id, url.to_string()))
+    .collect()
+}
+
+#[async_trait]
+impl VerificationBackend for BlockscoutBackend {
+    fn name(&self) -> &'static str {
+        "blockscout"
+    }
+
+    fn supports_chain(&self, chain_id: u64) -> bool {
+        self.endpoints.contains_key(&chain_id)
+    }
+
+    async fn verify(
+        &self,
+        contract: &ArtifactId,
+        chain_id: u64,
+        address: Address,
+        _constructor_args: &[u8],
+    ) -> Result<VerificationOutcome> {
+        let Some(endpoint) = self.endpoints.get(&chain_id) else {
+            bail!("No Blockscout endpoint configured for chain {chain_id}")
+        };
+
+        let url = format!("{endpoint}?module=contract&action=getsourcecode&address={address:#x}");
+        let response: BlockscoutGetSourceCodeResponse = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .wrap_err_with(|| format!("Failed to reach Blockscout at {endpoint}"))?
+            .error_for_status()
+            .wrap_err_with(|| format!("Blockscout at {endpoint} returned an error status"))?
+            .json()
+            .await
+            .wrap_err_with(|| format!("Failed to parse Blockscout's response from {endpoint}"))?;
+
+        let already_verified =
+            response.result.first().map(|entry| !entry.source_code.is_empty()).unwrap_or(false);
+
+        Ok(VerificationOutcome {
+            backend: self.name(),
+            success: already_verified,
+            message: if already_verified {
+                format!("{} is already verified on {endpoint}", contract.identifier())
+            } else {
+                format!(
+                    "{} is not verified on {endpoint}; submitting fresh sources requires the \
+                     compiled artifact bundle, which isn't available at this call site",
+                    contract.identifier()
+                )
+            },
+        })
+    }
+}
+
+/// Builds the set of backends requested via `--verifiers <name>` (repeatable), e.g.
+/// `--verifiers sourcify --verifiers blockscout`.
+pub fn backends_for(names: &[String]) -> Vec<Box<dyn VerificationBackend>> {
+    names
+        .iter()
+        .filter_map(|name| match name.as_str() {
+            "sourcify" => Some(Box::new(SourcifyBackend::new()) as Box<dyn VerificationBackend>),
+            "blockscout" => Some(
+                Box::new(BlockscoutBackend::new(default_blockscout_endpoints()))
+                    as Box<dyn VerificationBackend>,
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blockscout_supports_only_chains_with_a_configured_endpoint() {
+        let backend = BlockscoutBackend::new(default_blockscout_endpoints());
+        assert!(backend.supports_chain(1));
+        assert!(!backend.supports_chain(999_999));
+    }
+
+    #[test]
+    fn sourcify_supports_every_chain() {
+        let backend = SourcifyBackend::new();
+        assert!(backend.supports_chain(1));
+        assert!(backend.supports_chain(999_999));
+    }
+
+    #[test]
+    fn backends_for_ignores_unknown_names() {
+        let backends = backends_for(&["sourcify".to_string(), "not-a-real-backend".to_string()]);
+        assert_eq!(backends.len(), 1);
+        assert_eq!(backends[0].name(), "sourcify");
+    }
+}